@@ -0,0 +1,1258 @@
+use core::cmp::Ordering;
+
+use core::marker::PhantomData;
+
+use crate::AppliedTo;
+use crate::AppliedWith;
+use crate::Bb;
+use crate::CastSigned;
+use crate::CheckedIlog2;
+use crate::Ilog10;
+use crate::Ilog2;
+use crate::Isqrt;
+use crate::RA;
+use crate::SaturatingFrom;
+use crate::SaturatingNeg;
+
+#[doc(hidden)]
+macro_rules! impl_sat_add {
+    ($($name:ident: $Acc:ty),*) => {
+        impl Bb {
+            $(
+                #[doc = concat!("Adds `delta` to a `", stringify!($Acc), "` accumulator, saturating at `", stringify!($Acc), "::MAX`.")]
+                ///
+                /// `delta` is clamped into the accumulator type before the addition,
+                /// so increments wider than the accumulator can't wrap around.
+                pub const fn $name(acc: $Acc, delta: u32) -> $Acc {
+                    let delta = if delta > <$Acc>::MAX as u32 { <$Acc>::MAX } else { delta as $Acc };
+                    acc.saturating_add(delta)
+                }
+            )*
+        }
+    };
+}
+
+impl_sat_add!(sat_add_u8: u8, sat_add_u16: u16, sat_add_u32: u32);
+
+#[doc(hidden)]
+macro_rules! impl_sat_sum {
+    ($($name:ident: $T:ty),*) => {
+        impl Bb {
+            $(
+                #[doc = concat!("Sums `xs` into a `", stringify!($T), "`, saturating at `", stringify!($T), "::MAX` instead of overflowing.")]
+                pub const fn $name(xs: &[$T]) -> $T {
+                    let mut acc: $T = 0;
+                    let mut i = 0;
+                    while i < xs.len() {
+                        acc = acc.saturating_add(xs[i]);
+                        i += 1;
+                    }
+                    acc
+                }
+            )*
+        }
+    };
+}
+
+impl_sat_sum!(sat_sum_u8: u8, sat_sum_u16: u16, sat_sum_u32: u32);
+
+#[doc(hidden)]
+pub trait WideSum: Sized {
+    type Wide;
+
+    fn wide_sum(xs: &[Self]) -> Self::Wide;
+}
+
+#[doc(hidden)]
+macro_rules! impl_wide_sum {
+    ($($T:ty => $W:ty),*) => {
+        $(
+            impl WideSum for $T {
+                type Wide = $W;
+
+                fn wide_sum(xs: &[$T]) -> $W {
+                    xs.iter().map(|&x| x as $W).sum()
+                }
+            }
+        )*
+    };
+}
+
+impl_wide_sum!(i8 => i16, u8 => u16, i16 => i32, u16 => u32, i32 => i64, u32 => u64, i64 => i128, u64 => u128);
+
+impl Bb {
+    /// Sums `xs` into the type of double width.
+    ///
+    /// Up to `2^width` elements (`2^(width - 1)` for signed types) can't overflow the sum.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::wide_sum(&[200u8, 100]), 300u16);
+    /// ```
+    pub fn wide_sum<T: WideSum>(xs: &[T]) -> T::Wide {
+        T::wide_sum(xs)
+    }
+}
+
+#[doc(hidden)]
+pub trait SumInto<T>: Sized {
+    fn sum_into(xs: &[T]) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_sum_into {
+    ($($Acc:ty)*) => {
+        $(
+            impl<T: Copy> SumInto<T> for $Acc where $Acc: SaturatingFrom<T> {
+                fn sum_into(xs: &[T]) -> $Acc {
+                    xs.iter().fold(0, |acc: $Acc, &x| acc.saturating_add(<$Acc>::saturating_from(x)))
+                }
+            }
+        )*
+    };
+}
+
+impl_sum_into!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Sums `xs` into the accumulator type `Acc`, converting each element and every partial sum
+    /// with saturation, so the sum sticks at `Acc::MAX` (or `Acc::MIN`) instead of overflowing.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::sum_into::<u32, _>(&[u16::MAX; 4]), 4 * 0xffff);
+    /// assert_eq!(Bb::sum_into::<u8, _>(&[200u16, 100]), u8::MAX);
+    /// ```
+    pub fn sum_into<Acc: SumInto<T>, T>(xs: &[T]) -> Acc {
+        Acc::sum_into(xs)
+    }
+}
+
+#[doc(hidden)]
+pub trait Promote {
+    /// Sign and two's complement bits of the value widened to 128 bits.
+    fn promote(self) -> (bool, u128);
+}
+
+#[doc(hidden)]
+macro_rules! impl_promote {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl Promote for $S {
+                fn promote(self) -> (bool, u128) {
+                    (self < 0, self as i128 as u128)
+                }
+            }
+
+            impl Promote for $U {
+                fn promote(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_promote!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+impl Bb {
+    /// Compares two integers of arbitrary width and signedness by value.
+    ///
+    /// Both operands are widened to a common superset type first,
+    /// so `Bb::cmp(3u8, -1i32)` is `Greater` and `Bb::cmp(u64::MAX, -1i64)` is too.
+    pub fn cmp<A: Promote, B: Promote>(a: A, b: B) -> Ordering {
+        match (a.promote(), b.promote()) {
+            ((true, _), (false, _)) => Ordering::Less,
+            ((false, _), (true, _)) => Ordering::Greater,
+            // two's complement bits of same-sign values order like the values themselves
+            ((_, a), (_, b)) => a.cmp(&b),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait WideningMul {
+    type Wide;
+
+    fn wmul(self, rhs: Self) -> Self::Wide;
+}
+
+#[doc(hidden)]
+macro_rules! impl_wmul {
+    ($($T:ty => $W:ty),*) => {
+        $(
+            impl WideningMul for $T {
+                type Wide = $W;
+
+                fn wmul(self, rhs: $T) -> $W {
+                    self as $W * rhs as $W
+                }
+            }
+        )*
+    };
+}
+
+impl_wmul!(i8 => i16, u8 => u16, i16 => i32, u16 => u32, i32 => i64, u32 => u64, i64 => i128, u64 => u128);
+
+impl Bb {
+    /// Multiplies two integers into the type of double width, so the product is always exact.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::wmul(u32::MAX, u32::MAX), 0xffff_fffe_0000_0001u64);
+    /// ```
+    pub fn wmul<T: WideningMul>(a: T, b: T) -> T::Wide {
+        a.wmul(b)
+    }
+}
+
+#[doc(hidden)]
+pub trait Midpoint {
+    fn midpoint(self, rhs: Self) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_midpoint {
+    ($($T:ty)*) => {
+        $(
+            impl Midpoint for $T {
+                fn midpoint(self, rhs: $T) -> $T {
+                    // shared bits count fully, differing bits count half, so nothing can overflow
+                    (self & rhs) + ((self ^ rhs) >> 1)
+                }
+            }
+        )*
+    };
+}
+
+impl_midpoint!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Average of two integers, rounded towards negative infinity, without intermediate overflow.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::midpoint(u8::MAX, u8::MAX - 2), u8::MAX - 1);
+    /// assert_eq!(Bb::midpoint(-3i32, 0), -2);
+    /// ```
+    pub fn midpoint<T: Midpoint>(a: T, b: T) -> T {
+        a.midpoint(b)
+    }
+
+    /// Index of the first maximum of `xs`, `None` if it's empty.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::argmax(&[3u8, 9, 2, 9]), Some(1));
+    /// assert_eq!(Bb::argmax::<i32>(&[]), None);
+    /// ```
+    pub fn argmax<T: Ord>(xs: &[T]) -> Option<usize> {
+        (0..xs.len()).reduce(|best, i| match xs[i] > xs[best] {
+            true => i,
+            false => best,
+        })
+    }
+
+    /// Index of the first minimum of `xs`, `None` if it's empty.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::argmin(&[3i8, -9, 2, -9]), Some(1));
+    /// ```
+    pub fn argmin<T: Ord>(xs: &[T]) -> Option<usize> {
+        (0..xs.len()).reduce(|best, i| match xs[i] < xs[best] {
+            true => i,
+            false => best,
+        })
+    }
+}
+
+#[doc(hidden)]
+pub trait AbsDiff {
+    type Unsigned;
+
+    fn abs_diff(self, rhs: Self) -> Self::Unsigned;
+}
+
+#[doc(hidden)]
+macro_rules! impl_abs_diff {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl AbsDiff for $S {
+                type Unsigned = $U;
+
+                fn abs_diff(self, rhs: $S) -> $U {
+                    <$S>::abs_diff(self, rhs)
+                }
+            }
+
+            impl AbsDiff for $U {
+                type Unsigned = $U;
+
+                fn abs_diff(self, rhs: $U) -> $U {
+                    <$U>::abs_diff(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_diff!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+impl Bb {
+    /// Distance between `a` and `b` as the unsigned type of same width, which always holds it.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::abs_diff(i8::MIN, i8::MAX), 255u8);
+    /// assert_eq!(Bb::abs_diff(3u32, 10), 7);
+    /// ```
+    pub fn abs_diff<T: AbsDiff>(a: T, b: T) -> T::Unsigned {
+        a.abs_diff(b)
+    }
+}
+
+#[doc(hidden)]
+pub trait AddSigned: Sized {
+    type Signed;
+
+    fn checked_add_signed(self, delta: Self::Signed) -> Option<Self>;
+}
+
+#[doc(hidden)]
+macro_rules! impl_add_signed {
+    ($($U:ty, $S:ty);*) => {
+        $(
+            impl AddSigned for $U {
+                type Signed = $S;
+
+                fn checked_add_signed(self, delta: $S) -> Option<$U> {
+                    match delta < 0 {
+                        false => self.checked_add(delta as $U),
+                        true => self.checked_sub(delta.unsigned_abs()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_add_signed!(u8, i8; u16, i16; u32, i32; u64, i64; u128, i128; usize, isize);
+
+impl Bb {
+    /// Adds a signed `delta` to an unsigned `x`, producing `None` if the sum is out of range of `T`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::checked_add_signed(10u32, -3), Some(7));
+    /// assert_eq!(Bb::checked_add_signed(2u32, -3), None);
+    /// ```
+    pub fn checked_add_signed<T: AddSigned>(x: T, delta: T::Signed) -> Option<T> {
+        x.checked_add_signed(delta)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_ilog {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for Ilog2 {
+                type Output = u32;
+
+                fn apply(src: $T) -> u32 {
+                    src.ilog2()
+                }
+            }
+
+            impl AppliedTo<$T> for Ilog10 {
+                type Output = u32;
+
+                fn apply(src: $T) -> u32 {
+                    src.ilog10()
+                }
+            }
+
+            impl AppliedTo<$T> for CheckedIlog2 {
+                type Output = Option<u32>;
+
+                fn apply(src: $T) -> Option<u32> {
+                    src.checked_ilog2()
+                }
+            }
+        )*
+    };
+}
+
+impl_ilog!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+#[doc(hidden)]
+macro_rules! impl_isqrt {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for Isqrt {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    src.isqrt()
+                }
+            }
+        )*
+    };
+}
+
+impl_isqrt!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+#[doc(hidden)]
+macro_rules! impl_sneg {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for SaturatingNeg {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    src.saturating_neg()
+                }
+            }
+        )*
+    };
+}
+
+impl_sneg!(i8 i16 i32 i64 i128);
+
+#[doc(hidden)]
+pub trait Align: Sized {
+    fn align_up(self, align: Self) -> Self;
+
+    fn align_down(self, align: Self) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_align {
+    ($($T:ty)*) => {
+        $(
+            impl Align for $T {
+                fn align_up(self, align: $T) -> $T {
+                    debug_assert!(align.is_power_of_two(), "alignment must be a power of two");
+                    let mask = align.wrapping_sub(1);
+                    self.checked_add(mask).expect("aligned value overflows") & !mask
+                }
+
+                fn align_down(self, align: $T) -> $T {
+                    debug_assert!(align.is_power_of_two(), "alignment must be a power of two");
+                    self & !align.wrapping_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_align!(u8 u16 u32 u64 u128 usize);
+
+impl Bb {
+    /// Rounds `x` up to a multiple of `align`, which must be a power of two.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::align_up(0x1001usize, 0x1000), 0x2000);
+    /// assert_eq!(Bb::align_up(0x1000usize, 0x1000), 0x1000);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result isn't representable, and in debug builds if `align` isn't a power of two.
+    pub fn align_up<T: Align>(x: T, align: T) -> T {
+        x.align_up(align)
+    }
+
+    /// Rounds `x` down to a multiple of `align`, which must be a power of two.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `align` isn't a power of two.
+    pub fn align_down<T: Align>(x: T, align: T) -> T {
+        x.align_down(align)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct IsAligned(usize);
+
+impl<U> CastSigned<U> {
+    /// Tells whether the value is a multiple of `n`, which must be a power of two.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert!(0x1000usize * Bb.aligned(16));
+    /// assert!(!(0x1008usize * Bb.aligned(16)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `n` isn't a power of two.
+    pub fn aligned(&self, n: usize) -> RA<IsAligned, U> {
+        debug_assert!(n.is_power_of_two(), "alignment must be a power of two");
+        RA(IsAligned(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_is_aligned {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedWith<$T> for IsAligned {
+                type Output = bool;
+
+                fn apply_with(self, src: $T) -> bool {
+                    // alignments beyond the range of the type truncate to zero, which only zero satisfies
+                    src & (self.0 as $T).wrapping_sub(1) == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_is_aligned!(u8 u16 u32 u64 u128 usize);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct DivCeil<N>(N);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct NextMultiple<N>(N);
+
+impl<U> CastSigned<U> {
+    /// Divides an unsigned value by `n` of the same type, rounding up. Panics if `n` is zero.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(7u32 * Bb.divceil(4), 2);
+    /// assert_eq!(8u32 * Bb.divceil(4), 2);
+    /// ```
+    pub fn divceil<N>(&self, n: N) -> RA<DivCeil<N>, U> {
+        RA(DivCeil(n), PhantomData)
+    }
+
+    /// Rounds an unsigned value up to the nearest multiple of `n` of the same type, like `next_multiple_of`.
+    /// Panics if `n` is zero, and on overflow in debug builds.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(7usize * Bb.nextmul(4), 8);
+    /// assert_eq!(8usize * Bb.nextmul(4), 8);
+    /// ```
+    pub fn nextmul<N>(&self, n: N) -> RA<NextMultiple<N>, U> {
+        RA(NextMultiple(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_div_ceil {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedWith<$T> for DivCeil<$T> {
+                type Output = $T;
+
+                fn apply_with(self, src: $T) -> $T {
+                    src.div_ceil(self.0)
+                }
+            }
+
+            impl AppliedWith<$T> for NextMultiple<$T> {
+                type Output = $T;
+
+                fn apply_with(self, src: $T) -> $T {
+                    src.next_multiple_of(self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_div_ceil!(u8 u16 u32 u64 u128 usize);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct SaturatingShl(u32);
+
+impl<U> CastSigned<U> {
+    /// Shifts left by `n`, saturating to `MAX` (or `MIN` for negative values) if any significant bits would be lost.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(0x30u8 * Bb.shls(2), 0xc0);
+    /// assert_eq!(0x30u8 * Bb.shls(3), 0xff);
+    /// assert_eq!(-0x30i8 * Bb.shls(2), -0x80);
+    /// ```
+    pub fn shls(&self, n: u32) -> RA<SaturatingShl, U> {
+        RA(SaturatingShl(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_shls {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl AppliedWith<$U> for SaturatingShl {
+                type Output = $U;
+
+                fn apply_with(self, src: $U) -> $U {
+                    match self.0 {
+                        _ if src == 0 => 0,
+                        n if n >= <$U>::BITS || src > <$U>::MAX >> n => <$U>::MAX,
+                        n => src << n,
+                    }
+                }
+            }
+
+            impl AppliedWith<$S> for SaturatingShl {
+                type Output = $S;
+
+                fn apply_with(self, src: $S) -> $S {
+                    match self.0 {
+                        _ if src == 0 => 0,
+                        n if src > 0 && (n >= <$S>::BITS || src > <$S>::MAX >> n) => <$S>::MAX,
+                        n if src < 0 && (n >= <$S>::BITS || src < <$S>::MIN >> n) => <$S>::MIN,
+                        n => src << n,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_shls!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct CheckedShl(u32);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct CheckedShr(u32);
+
+impl<U> CastSigned<U> {
+    /// Shifts left by `n`, producing `None` if `n` isn't less than the bit width instead of masking it.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(1u32 * Bb.cshl(31), Some(0x8000_0000));
+    /// assert_eq!(1u32 * Bb.cshl(32), None);
+    /// ```
+    pub fn cshl(&self, n: u32) -> RA<CheckedShl, U> {
+        RA(CheckedShl(n), PhantomData)
+    }
+
+    /// Shifts right by `n`, producing `None` if `n` isn't less than the bit width instead of masking it.
+    pub fn cshr(&self, n: u32) -> RA<CheckedShr, U> {
+        RA(CheckedShr(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_checked_shift {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedWith<$T> for CheckedShl {
+                type Output = Option<$T>;
+
+                fn apply_with(self, src: $T) -> Option<$T> {
+                    src.checked_shl(self.0)
+                }
+            }
+
+            impl AppliedWith<$T> for CheckedShr {
+                type Output = Option<$T>;
+
+                fn apply_with(self, src: $T) -> Option<$T> {
+                    src.checked_shr(self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_shift!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct CheckedShlFor<T>(u32, PhantomData<T>);
+
+impl<U> CastSigned<U> {
+    /// Shifts left by `n`, producing `None` if `n` isn't less than the bit width of `T`,
+    /// the type the value is eventually cast to, rather than of the value itself.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(1u32 * Bb.checked_shl_for::<u8>(7), Some(0x80));
+    /// assert_eq!(1u32 * Bb.checked_shl_for::<u8>(8), None);
+    /// ```
+    pub fn checked_shl_for<T>(&self, n: u32) -> RA<CheckedShlFor<T>, U> {
+        RA(CheckedShlFor(n, PhantomData), PhantomData)
+    }
+}
+
+impl<S, T> AppliedWith<S> for CheckedShlFor<T> where CheckedShl: AppliedWith<S, Output = Option<S>> {
+    type Output = Option<S>;
+
+    fn apply_with(self, src: S) -> Option<S> {
+        match (self.0 as usize) < size_of::<T>() * 8 {
+            true => CheckedShl(self.0).apply_with(src),
+            false => None,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct ClampBits(u32);
+
+impl<U> CastSigned<U> {
+    /// Clamps the value into the range of an `n`-bit unsigned integer, `0..=2^n - 1`, keeping its type.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(5000u16 * Bb.clampbits(12), 0xfff);
+    /// assert_eq!(-5i16 * Bb.clampbits(12), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the bit width of the type.
+    pub fn clampbits(&self, n: u32) -> RA<ClampBits, U> {
+        RA(ClampBits(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_clampbits {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl AppliedWith<$U> for ClampBits {
+                type Output = $U;
+
+                fn apply_with(self, src: $U) -> $U {
+                    assert!(self.0 <= <$U>::BITS, "bit count exceeds the bit width");
+                    src.min(<$U>::MAX.checked_shr(<$U>::BITS - self.0).unwrap_or(0))
+                }
+            }
+
+            impl AppliedWith<$S> for ClampBits {
+                type Output = $S;
+
+                fn apply_with(self, src: $S) -> $S {
+                    self.apply_with(src.max(0) as $U) as $S
+                }
+            }
+        )*
+    };
+}
+
+impl_clampbits!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct SignedShift(i32);
+
+impl<U> CastSigned<U> {
+    /// Shifts left by `n` if it's positive, or right by `-n` if it's negative.
+    ///
+    /// The right shift is arithmetic for signed types and logical for unsigned ones.
+    /// Shifting by the bit width or more discards all bits, leaving zero or, shifting a negative value right, `-1`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(0x10u8 * Bb.shift(2), 0x40);
+    /// assert_eq!(0x10u8 * Bb.shift(-2), 0x04);
+    /// assert_eq!(-0x10i8 * Bb.shift(-100), -1);
+    /// ```
+    pub fn shift(&self, n: i32) -> RA<SignedShift, U> {
+        RA(SignedShift(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_signed_shift {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedWith<$T> for SignedShift {
+                type Output = $T;
+
+                fn apply_with(self, src: $T) -> $T {
+                    let n = self.0.unsigned_abs();
+                    match self.0 {
+                        0.. => src.checked_shl(n).unwrap_or(0),
+                        // one bit short of the width leaves copies of the sign bit, and one more clears unsigned values
+                        _ if n >= <$T>::BITS => src >> (<$T>::BITS - 1) >> 1,
+                        _ => src >> n,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_shift!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct RoundingShr(u32);
+
+impl<U> CastSigned<U> {
+    /// Shifts right by `n`, rounding to the nearest integer with ties to even
+    /// instead of truncating. The shift is arithmetic for signed types and logical for unsigned ones.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(7i8 * Bb.asr_round(1), 4);
+    /// assert_eq!(-7i8 * Bb.asr_round(2), -2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` isn't less than the bit width of the type.
+    pub fn asr_round(&self, n: u32) -> RA<RoundingShr, U> {
+        RA(RoundingShr(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_asr_round {
+    ($($T:ty, $U:ty);*) => {
+        $(
+            impl AppliedWith<$T> for RoundingShr {
+                type Output = $T;
+
+                fn apply_with(self, src: $T) -> $T {
+                    let n = self.0;
+                    assert!(n < <$T>::BITS, "shift amount must be less than the bit width");
+                    if n == 0 {
+                        return src;
+                    }
+                    let q = src >> n;
+                    let r = src as $U & <$U>::MAX >> (<$U>::BITS - n);
+                    let half = 1 << (n - 1);
+                    if r > half || r == half && q & 1 == 1 { q + 1 } else { q }
+                }
+            }
+        )*
+    };
+}
+
+impl_asr_round!(i8, u8; u8, u8; i16, u16; u16, u16; i32, u32; u32, u32; i64, u64; u64, u64; i128, u128; u128, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sat_add() {
+        assert_eq!(Bb::sat_add_u8(200, 55), 255);
+        assert_eq!(Bb::sat_add_u8(200, 56), 255);
+        assert_eq!(Bb::sat_add_u8(200, 54), 254);
+        assert_eq!(Bb::sat_add_u8(0, 0x1_01), 255);
+        assert_eq!(Bb::sat_add_u16(65000, 535), 65535);
+        assert_eq!(Bb::sat_add_u16(65000, 536), 65535);
+        assert_eq!(Bb::sat_add_u16(1, 0x1_0001), 65535);
+        assert_eq!(Bb::sat_add_u32(u32::MAX - 1, 1), u32::MAX);
+        assert_eq!(Bb::sat_add_u32(u32::MAX - 1, 2), u32::MAX);
+    }
+
+    #[test]
+    fn sat_sum() {
+        assert_eq!(Bb::sat_sum_u8(&[]), 0);
+        assert_eq!(Bb::sat_sum_u8(&[200, 55]), 255);
+        assert_eq!(Bb::sat_sum_u8(&[200, 100]), 255);
+        assert_eq!(Bb::sat_sum_u8(&[200, 100, 0]), 255);
+        assert_eq!(Bb::sat_sum_u8(&[1, 2, 3]), 6);
+        assert_eq!(Bb::sat_sum_u16(&[u16::MAX, 1]), u16::MAX);
+        assert_eq!(Bb::sat_sum_u32(&[u32::MAX - 1, 1]), u32::MAX);
+        const SUM: u8 = Bb::sat_sum_u8(&[100; 3]);
+        assert_eq!(SUM, 255);
+    }
+
+    #[test]
+    fn wide_sum() {
+        assert_eq!(Bb::wide_sum(&[u8::MAX; 257]), 257 * 255);
+        assert_eq!(Bb::wide_sum(&[i8::MIN; 256]), -0x8000);
+        assert_eq!(Bb::wide_sum(&[u32::MAX, u32::MAX]), 2 * u32::MAX as u64);
+        assert_eq!(Bb::wide_sum(&[u64::MAX, 1]), 1u128 << 64);
+        assert_eq!(Bb::wide_sum::<i16>(&[]), 0);
+    }
+
+    #[test]
+    fn sum_into() {
+        assert_eq!(Bb::sum_into::<u32, _>(&[u16::MAX, u16::MAX]), 2 * 0xffff);
+        assert_eq!(Bb::sum_into::<u32, u16>(&[]), 0);
+        assert_eq!(Bb::sum_into::<u16, _>(&[u16::MAX, 1]), u16::MAX);
+        assert_eq!(Bb::sum_into::<u64, _>(&[u32::MAX; 3]), 3 * u32::MAX as u64);
+        assert_eq!(Bb::sum_into::<i16, _>(&[i8::MIN; 300]), i16::MIN);
+        assert_eq!(Bb::sum_into::<i16, _>(&[i8::MAX; 300]), i16::MAX);
+        assert_eq!(Bb::sum_into::<i32, _>(&[-5i8, 3, 10]), 8);
+        // elements out of range of the accumulator saturate before the addition
+        assert_eq!(Bb::sum_into::<u8, _>(&[-1i32, 300, -1]), u8::MAX);
+        assert_eq!(Bb::sum_into::<u8, _>(&[-100i32, 5]), 5);
+        assert_eq!(Bb::sum_into::<u128, _>(&[u64::MAX; 2]), 2 * u64::MAX as u128);
+    }
+
+    #[test]
+    fn argmax_argmin() {
+        assert_eq!(Bb::argmax::<u8>(&[]), None);
+        assert_eq!(Bb::argmin::<u8>(&[]), None);
+        assert_eq!(Bb::argmax(&[7u64]), Some(0));
+        assert_eq!(Bb::argmin(&[7u64]), Some(0));
+        assert_eq!(Bb::argmax(&[1u8, 5, 5, 2]), Some(1));
+        assert_eq!(Bb::argmin(&[1u8, 5, 1, 0, 0]), Some(3));
+        assert_eq!(Bb::argmax(&[-5i32, -1, -3, -1]), Some(1));
+        assert_eq!(Bb::argmin(&[-5i32, -1, -3, -5]), Some(0));
+        assert_eq!(Bb::argmax(&[i128::MIN, i128::MIN]), Some(0));
+        assert_eq!(Bb::argmin(&[0u16, u16::MAX, 0]), Some(0));
+        assert_eq!(Bb::argmax(&[0i16, -1, i16::MAX]), Some(2));
+    }
+
+    #[test]
+    fn cmp() {
+        assert_eq!(Bb::cmp(3u8, -1i32), Ordering::Greater);
+        assert_eq!(Bb::cmp(-1i32, 3u8), Ordering::Less);
+        assert_eq!(Bb::cmp(u64::MAX, -1i64), Ordering::Greater);
+        assert_eq!(Bb::cmp(i64::MIN, u64::MIN), Ordering::Less);
+        assert_eq!(Bb::cmp(u32::MAX, -1i32), Ordering::Greater);
+        assert_eq!(Bb::cmp(u128::MAX, i128::MAX), Ordering::Greater);
+        assert_eq!(Bb::cmp(-2i8, -1i128), Ordering::Less);
+        assert_eq!(Bb::cmp(255u8, 255i16), Ordering::Equal);
+        assert_eq!(Bb::cmp(0u8, 0i8), Ordering::Equal);
+    }
+
+    #[test]
+    fn wmul() {
+        assert_eq!(Bb::wmul(u8::MAX, u8::MAX), 0xfe01u16);
+        assert_eq!(Bb::wmul(u16::MAX, u16::MAX), 0xfffe_0001u32);
+        assert_eq!(Bb::wmul(u32::MAX, u32::MAX), 0xffff_fffe_0000_0001u64);
+        assert_eq!(Bb::wmul(u64::MAX, u64::MAX), 0xffff_ffff_ffff_fffe_0000_0000_0000_0001u128);
+        assert_eq!(Bb::wmul(i8::MIN, i8::MIN), 0x4000i16);
+        assert_eq!(Bb::wmul(i8::MIN, i8::MAX), -0x3f80i16);
+        assert_eq!(Bb::wmul(i32::MIN, -1), 0x8000_0000i64);
+        assert_eq!(Bb::wmul(i64::MIN, i64::MIN), 1i128 << 126);
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(Bb::midpoint(u8::MAX, u8::MAX), u8::MAX);
+        assert_eq!(Bb::midpoint(u8::MAX, u8::MAX - 1), u8::MAX - 1);
+        assert_eq!(Bb::midpoint(u8::MAX, 0), 0x7f);
+        assert_eq!(Bb::midpoint(u32::MAX, 1), 0x8000_0000);
+        assert_eq!(Bb::midpoint(u128::MAX, u128::MAX - 2), u128::MAX - 1);
+        assert_eq!(Bb::midpoint(i8::MAX, i8::MAX), i8::MAX);
+        assert_eq!(Bb::midpoint(i8::MIN, i8::MIN), i8::MIN);
+        assert_eq!(Bb::midpoint(i8::MIN, i8::MAX), -1);
+        assert_eq!(Bb::midpoint(i16::MIN, i16::MIN + 2), i16::MIN + 1);
+        assert_eq!(Bb::midpoint(i64::MAX - 4, i64::MAX), i64::MAX - 2);
+        assert_eq!(Bb::midpoint(-5i32, -2), -4);
+        assert_eq!(Bb::midpoint(5i32, 2), 3);
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                assert_eq!(Bb::midpoint(a, b) as i16, (a as i16 + b as i16).div_euclid(2));
+            }
+        }
+    }
+
+    #[test]
+    fn checked_add_signed() {
+        assert_eq!(Bb::checked_add_signed(10u32, 5), Some(15));
+        assert_eq!(Bb::checked_add_signed(10u32, -10), Some(0));
+        assert_eq!(Bb::checked_add_signed(10u32, -11), None);
+        assert_eq!(Bb::checked_add_signed(u32::MAX - 1, 1), Some(u32::MAX));
+        assert_eq!(Bb::checked_add_signed(u32::MAX, 1), None);
+        assert_eq!(Bb::checked_add_signed(u8::MAX, i8::MIN), Some(127));
+        assert_eq!(Bb::checked_add_signed(127u8, i8::MIN), None);
+        assert_eq!(Bb::checked_add_signed(128u8, i8::MIN), Some(0));
+        assert_eq!(Bb::checked_add_signed(0u8, i8::MAX), Some(127));
+        assert_eq!(Bb::checked_add_signed(u128::MAX, i128::MIN), Some(u128::MAX >> 1));
+        assert_eq!(Bb::checked_add_signed(0usize, -1), None);
+        for x in [0u16, 1, 0x7fff, 0x8000, u16::MAX] {
+            for d in [i16::MIN, -1, 0, 1, i16::MAX] {
+                assert_eq!(Bb::checked_add_signed(x, d), x.checked_add_signed(d));
+            }
+        }
+    }
+
+    #[test]
+    fn abs_diff() {
+        assert_eq!(Bb::abs_diff(i8::MIN, i8::MAX), u8::MAX);
+        assert_eq!(Bb::abs_diff(i8::MAX, i8::MIN), u8::MAX);
+        assert_eq!(Bb::abs_diff(i128::MIN, i128::MAX), u128::MAX);
+        assert_eq!(Bb::abs_diff(i32::MIN, 0), 0x8000_0000u32);
+        assert_eq!(Bb::abs_diff(-5i16, 3), 8);
+        assert_eq!(Bb::abs_diff(-5i16, -5), 0);
+        assert_eq!(Bb::abs_diff(0u64, u64::MAX), u64::MAX);
+        assert_eq!(Bb::abs_diff(10u8, 3), 7);
+        assert_eq!(Bb::abs_diff(3u8, 10), 7);
+    }
+
+    #[test]
+    fn ilog() {
+        for n in 0..8 {
+            assert_eq!((1u8 << n) * Bb.ilog2, n);
+            assert_eq!((u8::MAX >> (7 - n)) * Bb.ilog2, n);
+        }
+        assert_eq!(u128::MAX * Bb.ilog2, 127);
+        assert_eq!(i64::MAX * Bb.ilog2, 62);
+        assert_eq!(0x8000u16 * Bb.s.checked_ilog2, None);
+        assert_eq!(0u32 * Bb.checked_ilog2, None);
+        assert_eq!(0x100u32 * Bb.checked_ilog2, Some(8));
+        assert_eq!(0xffu32 * Bb.checked_ilog2, Some(7));
+        let mut p = 1u64;
+        for n in 0..19 {
+            assert_eq!(p * Bb.ilog10, n);
+            assert_eq!((p * 10 - 1) * Bb.ilog10, n);
+            p *= 10;
+        }
+        assert_eq!(u8::MAX * Bb.ilog10, 2);
+        assert_eq!(99i8 * Bb.ilog10, 1);
+        assert_eq!(0x1234u16 * Bb.ilog2.t8, 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_zero() {
+        let _ = 0u32 * Bb.ilog2;
+    }
+
+    #[test]
+    fn isqrt() {
+        for n in 0..=u16::MAX {
+            assert_eq!(n * Bb.isqrt, (n as f64).sqrt().floor() as u16);
+        }
+        for n in (0..=i32::MAX).step_by(9973) {
+            assert_eq!(n * Bb.isqrt, (n as f64).sqrt().floor() as i32);
+        }
+        for r in [1u64, 3, 1 << 16, 0xffff_ffff] {
+            assert_eq!((r * r) * Bb.isqrt, r);
+            assert_eq!((r * r - 1) * Bb.isqrt, r - 1);
+        }
+        assert_eq!(u8::MAX * Bb.isqrt, 15);
+        assert_eq!(u128::MAX * Bb.isqrt, u64::MAX as u128);
+        assert_eq!(i8::MAX * Bb.isqrt, 11);
+    }
+
+    #[test]
+    #[should_panic]
+    fn isqrt_negative() {
+        let _ = -1i32 * Bb.isqrt;
+    }
+
+    #[test]
+    fn sneg() {
+        assert_eq!(i8::MIN * Bb.sneg, i8::MAX);
+        assert_eq!(i8::MAX * Bb.sneg, i8::MIN + 1);
+        assert_eq!(-1i8 * Bb.sneg, 1);
+        assert_eq!(0i32 * Bb.sneg, 0);
+        assert_eq!(i128::MIN * Bb.sneg, i128::MAX);
+        assert_eq!(0x80u8 * Bb.s.sneg.u, 0x7f);
+    }
+
+    #[test]
+    fn align() {
+        assert_eq!(Bb::align_up(0u32, 8), 0);
+        assert_eq!(Bb::align_up(8u32, 8), 8);
+        assert_eq!(Bb::align_up(9u32, 8), 16);
+        assert_eq!(Bb::align_up(15u32, 8), 16);
+        assert_eq!(Bb::align_up(15u32, 1), 15);
+        assert_eq!(Bb::align_up(0xf0u8, 0x10), 0xf0);
+        assert_eq!(Bb::align_up(0xefu8, 0x10), 0xf0);
+        assert_eq!(Bb::align_up(u64::MAX - 7, 8), u64::MAX - 7);
+        assert_eq!(Bb::align_up(1u128, 1 << 127), 1 << 127);
+        assert_eq!(Bb::align_down(15u32, 8), 8);
+        assert_eq!(Bb::align_down(16u32, 8), 16);
+        assert_eq!(Bb::align_down(7u32, 8), 0);
+        assert_eq!(Bb::align_down(u8::MAX, 0x80), 0x80);
+        assert_eq!(Bb::align_down(u64::MAX, 1), u64::MAX);
+        assert_eq!(Bb::align_down(0x1234usize, 0x100), 0x1200);
+    }
+
+    #[test]
+    fn aligned() {
+        assert!(0usize * Bb.aligned(16));
+        assert!(0x1000usize * Bb.aligned(16));
+        assert!(0x1000usize * Bb.aligned(0x1000));
+        assert!(!(0x1000usize * Bb.aligned(0x2000)));
+        assert!(!(0x1008usize * Bb.aligned(16)));
+        assert!(0x1008usize * Bb.aligned(8));
+        assert!(7u32 * Bb.aligned(1));
+        assert!(!(0x80u8 * Bb.aligned(0x100)));
+        assert!(0u8 * Bb.aligned(0x100));
+        assert!((1u128 << 100) * Bb.aligned(1 << 63));
+        assert!(!(0x1234u16 * Bb.aligned(8)));
+    }
+
+    #[test]
+    fn checked_shl_for() {
+        assert_eq!(1u32 * Bb.checked_shl_for::<u8>(0), Some(1));
+        assert_eq!(1u32 * Bb.checked_shl_for::<u8>(7), Some(0x80));
+        // valid for the u32 source, but not for the u8 target
+        assert_eq!(1u32 * Bb.cshl(8), Some(0x100));
+        assert_eq!(1u32 * Bb.checked_shl_for::<u8>(8), None);
+        assert_eq!(1u32 * Bb.checked_shl_for::<u16>(20), None);
+        assert_eq!(1u32 * Bb.checked_shl_for::<u32>(31), Some(1 << 31));
+        // a wider target doesn't allow shifting past the source width
+        assert_eq!(1u32 * Bb.checked_shl_for::<u64>(40), None);
+        assert_eq!(1u64 * Bb.checked_shl_for::<i16>(15), Some(1 << 15));
+        assert_eq!((0xffu16 * Bb.checked_shl_for::<u8>(4)).map(|x| x as u8), Some(0xf0));
+    }
+
+    #[test]
+    fn divceil_nextmul() {
+        assert_eq!(0u32 * Bb.divceil(4), 0);
+        assert_eq!(1u32 * Bb.divceil(4), 1);
+        assert_eq!(4u32 * Bb.divceil(4), 1);
+        assert_eq!(5u32 * Bb.divceil(4), 2);
+        assert_eq!(u8::MAX * Bb.divceil(2), 128);
+        assert_eq!(u8::MAX * Bb.divceil(u8::MAX), 1);
+        assert_eq!(u128::MAX * Bb.divceil(1), u128::MAX);
+        assert_eq!(0x1234u16 * Bb.e32.divceil(0x100), 0x13);
+        assert_eq!(0usize * Bb.nextmul(8), 0);
+        assert_eq!(1usize * Bb.nextmul(8), 8);
+        assert_eq!(8usize * Bb.nextmul(8), 8);
+        assert_eq!(9usize * Bb.nextmul(8), 16);
+        assert_eq!(10u64 * Bb.nextmul(3), 12);
+        assert_eq!(250u8 * Bb.nextmul(5), 250);
+        assert_eq!(u8::MAX * Bb.nextmul(u8::MAX), u8::MAX);
+        for x in 0..=200u8 {
+            for n in [1, 3, 7, 50] {
+                assert_eq!(x * Bb.divceil(n), x.div_ceil(n));
+                assert_eq!(x * Bb.nextmul(n), x.next_multiple_of(n));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn divceil_zero() {
+        let _ = 5u32 * Bb.divceil(0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn nextmul_overflow() {
+        let _ = 253u8 * Bb.nextmul(7);
+    }
+
+    #[test]
+    #[should_panic = "aligned value overflows"]
+    fn align_up_overflow() {
+        let _ = Bb::align_up(0xf1u8, 0x10);
+    }
+
+    #[test]
+    fn shls() {
+        assert_eq!(1u8 * Bb.shls(7), 0x80);
+        assert_eq!(1u8 * Bb.shls(8), 0xff);
+        assert_eq!(0u8 * Bb.shls(100), 0);
+        assert_eq!(0x7fu8 * Bb.shls(1), 0xfe);
+        assert_eq!(0x80u8 * Bb.shls(1), 0xff);
+        assert_eq!(0x1234u16 * Bb.shls(0), 0x1234);
+        assert_eq!(1i8 * Bb.shls(6), 0x40);
+        assert_eq!(1i8 * Bb.shls(7), i8::MAX);
+        assert_eq!(-1i8 * Bb.shls(7), i8::MIN);
+        assert_eq!(-1i8 * Bb.shls(8), i8::MIN);
+        assert_eq!(-0x41i8 * Bb.shls(1), i8::MIN);
+        assert_eq!(-0x40i8 * Bb.shls(1), -0x80);
+        assert_eq!(5i64 * Bb.shls(60), 5 << 60);
+        assert_eq!(5i64 * Bb.shls(61), i64::MAX);
+        assert_eq!(-5i64 * Bb.shls(59), -5 << 59);
+        assert_eq!(0xffu8 * Bb.e32.shls(24), 0xff00_0000);
+        assert_eq!(u128::MAX * Bb.shls(1), u128::MAX);
+    }
+
+    #[test]
+    fn checked_shift() {
+        assert_eq!(1u8 * Bb.cshl(0), Some(1));
+        assert_eq!(1u8 * Bb.cshl(7), Some(0x80));
+        assert_eq!(1u8 * Bb.cshl(8), None);
+        assert_eq!(0u64 * Bb.cshl(64), None);
+        assert_eq!(0x80u8 * Bb.cshr(7), Some(1));
+        assert_eq!(0x80u8 * Bb.cshr(8), None);
+        assert_eq!(-0x80i8 * Bb.cshr(7), Some(-1));
+        assert_eq!(u128::MAX * Bb.cshr(127), Some(1));
+        assert_eq!(u128::MAX * Bb.cshr(128), None);
+        assert_eq!(0xffu8 * Bb.e32.cshl(24), Some(0xff00_0000));
+        assert_eq!(0xffu8 * Bb.e32.cshl(u32::MAX), None);
+    }
+
+    #[test]
+    fn clampbits() {
+        assert_eq!(0xfffu16 * Bb.clampbits(12), 0xfff);
+        assert_eq!(0x1000u16 * Bb.clampbits(12), 0xfff);
+        assert_eq!(u16::MAX * Bb.clampbits(12), 0xfff);
+        assert_eq!(0x123u16 * Bb.clampbits(12), 0x123);
+        assert_eq!(u16::MAX * Bb.clampbits(16), u16::MAX);
+        assert_eq!(u16::MAX * Bb.clampbits(0), 0);
+        assert_eq!(-1i32 * Bb.clampbits(12), 0);
+        assert_eq!(i32::MIN * Bb.clampbits(32), 0);
+        assert_eq!(5000i32 * Bb.clampbits(12), 0xfff);
+        assert_eq!(i32::MAX * Bb.clampbits(32), i32::MAX);
+        assert_eq!(i32::MAX * Bb.clampbits(31), i32::MAX);
+        assert_eq!(i32::MAX * Bb.clampbits(30), i32::MAX >> 1);
+        assert_eq!(u128::MAX * Bb.clampbits(100), u128::MAX >> 28);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clampbits_overlong() {
+        let _ = 0u8 * Bb.clampbits(9);
+    }
+
+    #[test]
+    fn shift() {
+        assert_eq!(0x10u8 * Bb.shift(0), 0x10);
+        assert_eq!(0x10u8 * Bb.shift(3), 0x80);
+        assert_eq!(0x10u8 * Bb.shift(4), 0);
+        assert_eq!(0x10u8 * Bb.shift(-4), 1);
+        assert_eq!(0x80u8 * Bb.shift(-7), 1);
+        assert_eq!(0x80u8 * Bb.shift(-8), 0);
+        assert_eq!(0xffu8 * Bb.shift(8), 0);
+        assert_eq!(0xffu8 * Bb.shift(i32::MAX), 0);
+        assert_eq!(0xffu8 * Bb.shift(i32::MIN), 0);
+        assert_eq!(-0x80i8 * Bb.shift(-7), -1);
+        assert_eq!(-0x80i8 * Bb.shift(-8), -1);
+        assert_eq!(-0x80i8 * Bb.shift(i32::MIN), -1);
+        assert_eq!(0x7fi8 * Bb.shift(-8), 0);
+        assert_eq!(-1i32 * Bb.shift(31), i32::MIN);
+        assert_eq!(-1i32 * Bb.shift(32), 0);
+        assert_eq!(-8i64 * Bb.shift(-2), -2);
+        assert_eq!(u128::MAX * Bb.shift(-127), 1);
+        assert_eq!(u128::MAX * Bb.shift(-128), 0);
+    }
+
+    #[test]
+    fn asr_round() {
+        assert_eq!(0x1234i16 * Bb.asr_round(0), 0x1234);
+        // 5 / 2 = 2.5, 7 / 2 = 3.5, ties to even
+        assert_eq!(5i32 * Bb.asr_round(1), 2);
+        assert_eq!(7i32 * Bb.asr_round(1), 4);
+        assert_eq!(-5i32 * Bb.asr_round(1), -2);
+        assert_eq!(-7i32 * Bb.asr_round(1), -4);
+        // 9 / 4 = 2.25, 11 / 4 = 2.75
+        assert_eq!(9i32 * Bb.asr_round(2), 2);
+        assert_eq!(11i32 * Bb.asr_round(2), 3);
+        assert_eq!(-9i32 * Bb.asr_round(2), -2);
+        assert_eq!(-11i32 * Bb.asr_round(2), -3);
+        assert_eq!(-6i32 * Bb.asr_round(2), -2);
+        assert_eq!(i8::MIN * Bb.asr_round(7), -1);
+        assert_eq!(i8::MAX * Bb.asr_round(7), 1);
+        assert_eq!(i8::MAX * Bb.asr_round(1), 64);
+        assert_eq!(u8::MAX * Bb.asr_round(1), 128);
+        assert_eq!(u8::MAX * Bb.asr_round(7), 2);
+        assert_eq!(0x80u8 * Bb.asr_round(7), 1);
+        assert_eq!(0x180u16 * Bb.asr_round(8), 2);
+        assert_eq!(0x280u16 * Bb.asr_round(8), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn asr_round_overlong() {
+        let _ = 1u8 * Bb.asr_round(8);
+    }
+}
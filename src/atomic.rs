@@ -0,0 +1,72 @@
+use core::sync::atomic::Ordering;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+
+use crate::Bb;
+use crate::CastFrom;
+
+#[doc(hidden)]
+pub trait AtomicCast<T> {
+    fn load_cast(&self, order: Ordering) -> T;
+}
+
+#[doc(hidden)]
+macro_rules! impl_atomic_cast {
+    ($($width:literal: $($A:ty => $V:ty),*);*) => {
+        $(
+            $(
+                #[cfg(target_has_atomic = $width)]
+                impl<T: CastFrom<$V>> AtomicCast<T> for $A {
+                    fn load_cast(&self, order: Ordering) -> T {
+                        T::cast_from(self.load(order))
+                    }
+                }
+            )*
+        )*
+    };
+}
+
+impl_atomic_cast! {
+    "8": AtomicI8 => i8, AtomicU8 => u8;
+    "16": AtomicI16 => i16, AtomicU16 => u16;
+    "32": AtomicI32 => i32, AtomicU32 => u32;
+    "64": AtomicI64 => i64, AtomicU64 => u64
+}
+
+impl Bb {
+    /// Loads from an atomic integer with the given ordering and casts the value to `T` with [CastFrom].
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// use core::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// let counter = AtomicU32::new(0x1234);
+    /// assert_eq!(Bb::load_cast::<u8>(&counter, Ordering::Relaxed), 0x34);
+    /// ```
+    pub fn load_cast<T>(atomic: &impl AtomicCast<T>, order: Ordering) -> T {
+        atomic.load_cast(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_has_atomic = "32")]
+    fn load_cast() {
+        let counter = AtomicU32::new(0x1_ff80);
+        assert_eq!(Bb::load_cast::<u8>(&counter, Ordering::Relaxed), 0x80);
+        assert_eq!(Bb::load_cast::<i8>(&counter, Ordering::Acquire), -0x80);
+        assert_eq!(Bb::load_cast::<u16>(&counter, Ordering::SeqCst), 0xff80);
+        assert_eq!(Bb::load_cast::<i64>(&counter, Ordering::Relaxed), 0x1_ff80);
+        let signed = AtomicI32::new(-1);
+        assert_eq!(Bb::load_cast::<u64>(&signed, Ordering::Relaxed), u64::MAX);
+    }
+}
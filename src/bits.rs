@@ -0,0 +1,1612 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::AppliedTo;
+use crate::AppliedWith;
+use crate::Bb;
+use crate::CastSigned;
+use crate::ClearLowest;
+use crate::CountOnes;
+use crate::LeadingRun;
+use crate::MaskToLowest;
+use crate::NormMsb;
+use crate::RA;
+use crate::ReverseBits;
+use crate::TrailingRun;
+
+#[doc(hidden)]
+pub trait BitPermute {
+    fn pext(self, mask: Self) -> Self;
+
+    fn pdep(self, mask: Self) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_bit_permute {
+    ($($S:ty > $U:ty),*) => {
+        $(
+            impl BitPermute for $U {
+                fn pext(self, mask: $U) -> $U {
+                    let mut res = 0;
+                    let mut bit = 1;
+                    let mut mask = mask;
+                    while mask != 0 {
+                        if self & mask & mask.wrapping_neg() != 0 {
+                            res |= bit;
+                        }
+                        mask &= mask - 1;
+                        bit <<= 1;
+                    }
+                    res
+                }
+
+                fn pdep(self, mask: $U) -> $U {
+                    let mut res = 0;
+                    let mut bit = 1;
+                    let mut mask = mask;
+                    while mask != 0 {
+                        if self & bit != 0 {
+                            res |= mask & mask.wrapping_neg();
+                        }
+                        mask &= mask - 1;
+                        bit <<= 1;
+                    }
+                    res
+                }
+            }
+
+            impl BitPermute for $S {
+                fn pext(self, mask: $S) -> $S {
+                    (self as $U).pext(mask as $U) as $S
+                }
+
+                fn pdep(self, mask: $S) -> $S {
+                    (self as $U).pdep(mask as $U) as $S
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_permute!(i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+
+impl Bb {
+    /// Parallel bit extract: gathers the bits of `x` selected by `mask` into the low bits of the result.
+    ///
+    /// Matches the semantics of x86 BMI2 `pext`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::pext(0b1011_0110u8, 0b1111_0000), 0b1011);
+    /// ```
+    pub fn pext<T: BitPermute>(x: T, mask: T) -> T {
+        x.pext(mask)
+    }
+
+    /// Parallel bit deposit: scatters the low bits of `x` into the positions selected by `mask`.
+    ///
+    /// Matches the semantics of x86 BMI2 `pdep`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::pdep(0b1011u8, 0b1111_0000), 0b1011_0000);
+    /// ```
+    pub fn pdep<T: BitPermute>(x: T, mask: T) -> T {
+        x.pdep(mask)
+    }
+}
+
+#[doc(hidden)]
+pub trait CollapseRuns: Sized {
+    fn collapse_runs(self) -> (Self, u32);
+}
+
+#[doc(hidden)]
+macro_rules! impl_collapse_runs {
+    ($($T:ty)*) => {
+        $(
+            impl CollapseRuns for $T {
+                fn collapse_runs(self) -> ($T, u32) {
+                    // bits that differ from the bit below them start a run, as does bit 0
+                    let starts = (self ^ self << 1) | 1;
+                    (self.pext(starts), starts.count_ones())
+                }
+            }
+        )*
+    };
+}
+
+impl_collapse_runs!(u8 u16 u32 u64 u128);
+
+impl Bb {
+    /// Replaces each maximal run of equal bits of `x` with a single bit, packed towards the
+    /// least significant bit, producing `(value, run_count)`. The bit of the lowest run ends up in bit 0.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::collapse_runs(0b0011_0110u8), (0b01010, 5));
+    /// assert_eq!(Bb::collapse_runs(0u8), (0, 1));
+    /// ```
+    pub fn collapse_runs<T: CollapseRuns>(x: T) -> (T, u32) {
+        x.collapse_runs()
+    }
+}
+
+#[doc(hidden)]
+pub trait CarrylessMul {
+    type Wide;
+
+    fn clmul(self, rhs: Self) -> Self::Wide;
+}
+
+#[doc(hidden)]
+macro_rules! impl_clmul {
+    ($($T:ty => $W:ty),*) => {
+        $(
+            impl CarrylessMul for $T {
+                type Wide = $W;
+
+                fn clmul(self, rhs: $T) -> $W {
+                    let a = self as $W;
+                    let mut b = rhs;
+                    let mut res = 0;
+                    while b != 0 {
+                        res ^= a << b.trailing_zeros();
+                        b &= b - 1;
+                    }
+                    res
+                }
+            }
+        )*
+    };
+}
+
+impl_clmul!(u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+
+impl Bb {
+    /// Carry-less (GF(2) polynomial) multiplication into the type of double width.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::clmul(0b11u8, 0b11), 0b101u16);
+    /// ```
+    pub fn clmul<T: CarrylessMul>(a: T, b: T) -> T::Wide {
+        a.clmul(b)
+    }
+}
+
+#[doc(hidden)]
+pub trait SpreadBits {
+    type Wide;
+
+    fn spread_bits(self, stride: u32) -> Self::Wide;
+}
+
+#[doc(hidden)]
+macro_rules! impl_spread_bits {
+    ($($T:ty => $W:ty),*) => {
+        $(
+            impl SpreadBits for $T {
+                type Wide = $W;
+
+                fn spread_bits(self, stride: u32) -> $W {
+                    assert!((1..=4).contains(&stride), "stride must be in 1..=4");
+                    let mut x = self;
+                    let mut res = 0;
+                    while x != 0 {
+                        res |= 1 << (x.trailing_zeros() * stride);
+                        x &= x - 1;
+                    }
+                    res
+                }
+            }
+        )*
+    };
+}
+
+impl_spread_bits!(u8 => u32, u16 => u64, u32 => u128);
+
+impl Bb {
+    /// Spreads the bits of `x` apart, moving bit `i` to bit `i * stride` with zeros in between,
+    /// into the type of quadruple width.
+    ///
+    /// Stride 2 is the Morton (Z-order) spread: `spread_bits(x, 2) | spread_bits(y, 2) << 1`
+    /// interleaves two coordinates, and stride 3 does the same for three.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::spread_bits(0b111u16, 2), 0b10101u64);
+    /// assert_eq!(Bb::spread_bits(0b111u16, 3), 0b1001001u64);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` isn't in `1..=4`.
+    pub fn spread_bits<T: SpreadBits>(x: T, stride: u32) -> T::Wide {
+        x.spread_bits(stride)
+    }
+
+    /// 3D Morton (Z-order) code of 10-bit coordinates: bit `i` of `x`, `y` and `z` goes to
+    /// bit `3 * i`, `3 * i + 1` and `3 * i + 2` of the code. Bits above the low 10 are ignored.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::morton3(0b11, 0b01, 0b10), 0b101_011);
+    /// ```
+    pub fn morton3(x: u16, y: u16, z: u16) -> u32 {
+        fn spread(x: u16) -> u32 {
+            let x = x as u32 & 0x3ff;
+            let x = (x | x << 16) & 0x0300_00ff;
+            let x = (x | x << 8) & 0x0300_f00f;
+            let x = (x | x << 4) & 0x030c_30c3;
+            (x | x << 2) & 0x0924_9249
+        }
+
+        spread(x) | spread(y) << 1 | spread(z) << 2
+    }
+
+    /// Splits a 3D Morton code into its `(x, y, z)` coordinates, the inverse of [morton3](Bb::morton3).
+    /// The top two bits of `code` are ignored.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::demorton3(0b101_011), (0b11, 0b01, 0b10));
+    /// ```
+    pub fn demorton3(code: u32) -> (u16, u16, u16) {
+        fn compact(x: u32) -> u16 {
+            let x = x & 0x0924_9249;
+            let x = (x | x >> 2) & 0x030c_30c3;
+            let x = (x | x >> 4) & 0x0300_f00f;
+            let x = (x | x >> 8) & 0x0300_00ff;
+            ((x | x >> 16) & 0x3ff) as u16
+        }
+
+        (compact(code), compact(code >> 1), compact(code >> 2))
+    }
+}
+
+#[doc(hidden)]
+pub trait BitScan {
+    fn bsr(self) -> Option<u32>;
+
+    fn bsf(self) -> Option<u32>;
+}
+
+#[doc(hidden)]
+macro_rules! impl_bit_scan {
+    ($($T:ty)*) => {
+        $(
+            impl BitScan for $T {
+                fn bsr(self) -> Option<u32> {
+                    match self {
+                        0 => None,
+                        x => Some(<$T>::BITS - 1 - x.leading_zeros()),
+                    }
+                }
+
+                fn bsf(self) -> Option<u32> {
+                    match self {
+                        0 => None,
+                        x => Some(x.trailing_zeros()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_scan!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Index of the highest set bit, like the x86 `bsr` instruction, or `None` for zero where `bsr` is undefined.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bsr(0b0110u8), Some(2));
+    /// assert_eq!(Bb::bsr(0u8), None);
+    /// ```
+    pub fn bsr<T: BitScan>(x: T) -> Option<u32> {
+        x.bsr()
+    }
+
+    /// Index of the lowest set bit, like the x86 `bsf` instruction, or `None` for zero where `bsf` is undefined.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bsf(0b0110u8), Some(1));
+    /// assert_eq!(Bb::bsf(0u8), None);
+    /// ```
+    pub fn bsf<T: BitScan>(x: T) -> Option<u32> {
+        x.bsf()
+    }
+}
+
+#[doc(hidden)]
+pub trait BitCounts: Sized {
+    type Counts;
+
+    fn bit_counts(xs: &[Self]) -> Self::Counts;
+}
+
+#[doc(hidden)]
+macro_rules! impl_bit_counts {
+    ($($T:ty)*) => {
+        $(
+            impl BitCounts for $T {
+                type Counts = [u32; <$T>::BITS as usize];
+
+                fn bit_counts(xs: &[$T]) -> Self::Counts {
+                    let mut counts = [0; <$T>::BITS as usize];
+                    for &x in xs {
+                        for (i, c) in counts.iter_mut().enumerate() {
+                            *c += (x >> i & 1) as u32;
+                        }
+                    }
+                    counts
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_counts!(u8 u16 u32 u64 u128);
+
+impl Bb {
+    /// Counts, for every bit position, how many elements of `xs` have that bit set.
+    /// `counts[i]` is the count for bit `i`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bit_counts(&[0b11u8, 0b10, 0b110]), [1, 3, 1, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn bit_counts<T: BitCounts>(xs: &[T]) -> T::Counts {
+        T::bit_counts(xs)
+    }
+}
+
+#[doc(hidden)]
+pub trait Hamming {
+    fn hamming(self, rhs: Self) -> u32;
+}
+
+#[doc(hidden)]
+macro_rules! impl_hamming {
+    ($($T:ty)*) => {
+        $(
+            impl Hamming for $T {
+                fn hamming(self, rhs: $T) -> u32 {
+                    (self ^ rhs).count_ones()
+                }
+            }
+        )*
+    };
+}
+
+impl_hamming!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Number of bit positions at which `a` and `b` differ.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::hamming(0b1011u8, 0b0001), 2);
+    /// ```
+    pub fn hamming<T: Hamming>(a: T, b: T) -> u32 {
+        a.hamming(b)
+    }
+}
+
+#[doc(hidden)]
+pub trait CommonPrefix {
+    fn common_prefix_len(self, rhs: Self) -> u32;
+}
+
+#[doc(hidden)]
+macro_rules! impl_common_prefix {
+    ($($T:ty)*) => {
+        $(
+            impl CommonPrefix for $T {
+                fn common_prefix_len(self, rhs: $T) -> u32 {
+                    (self ^ rhs).leading_zeros()
+                }
+            }
+        )*
+    };
+}
+
+impl_common_prefix!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize);
+
+impl Bb {
+    /// Length of the longest run of most significant bits on which `a` and `b` agree,
+    /// the full bit width if they are equal.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::common_prefix_len(0b1011_0000u8, 0b1010_1111), 3);
+    /// ```
+    pub fn common_prefix_len<T: CommonPrefix>(a: T, b: T) -> u32 {
+        a.common_prefix_len(b)
+    }
+}
+
+#[doc(hidden)]
+pub trait LowMask {
+    fn low_mask(n: u32) -> Self;
+
+    fn replicate(b: bool, n: u32) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_low_mask {
+    ($($T:ty, $U:ty);*) => {
+        $(
+            impl LowMask for $T {
+                fn low_mask(n: u32) -> $T {
+                    assert!(n <= <$U>::BITS, "mask is wider than the type");
+                    <$U>::MAX.checked_shr(<$U>::BITS - n).unwrap_or(0) as $T
+                }
+
+                fn replicate(b: bool, n: u32) -> $T {
+                    Self::low_mask(n) & (b as $T).wrapping_neg()
+                }
+            }
+        )*
+    };
+}
+
+impl_low_mask!(i8, u8; u8, u8; i16, u16; u16, u16; i32, u32; u32, u32; i64, u64; u64, u64; i128, u128; u128, u128);
+
+impl Bb {
+    /// Value of type `T` with the low `n` bits set and the rest clear.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::mask::<u32>(13), 0x1fff);
+    /// assert_eq!(Bb::mask::<i8>(8), -1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the bit width of `T`.
+    pub fn mask<T: LowMask>(n: u32) -> T {
+        T::low_mask(n)
+    }
+
+    /// Value of type `T` with the low `n` bits equal to `b`, and the rest clear. Doesn't branch on `b`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::replicate_bit::<u32>(true, 12), 0xfff);
+    /// assert_eq!(Bb::replicate_bit::<u32>(false, 12), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the bit width of `T`.
+    pub fn replicate_bit<T: LowMask>(b: bool, n: u32) -> T {
+        T::replicate(b, n)
+    }
+}
+
+#[doc(hidden)]
+pub trait BitSelect {
+    fn select(self, b: Self, mask: Self) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_select {
+    ($($T:ty)*) => {
+        $(
+            impl BitSelect for $T {
+                fn select(self, b: $T, mask: $T) -> $T {
+                    self & !mask | b & mask
+                }
+            }
+        )*
+    };
+}
+
+impl_select!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Blends two values bitwise: bits set in `mask` are taken from `b`, the rest from `a`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::select(0x1234u16, 0xabcd, 0x0ff0), 0x1bc4);
+    /// ```
+    pub fn select<T: BitSelect>(a: T, b: T, mask: T) -> T {
+        a.select(b, mask)
+    }
+}
+
+#[doc(hidden)]
+pub trait RotateCarry: Sized {
+    fn rcl(self, carry: bool) -> (Self, bool);
+
+    fn rcr(self, carry: bool) -> (Self, bool);
+}
+
+#[doc(hidden)]
+macro_rules! impl_rotate_carry {
+    ($($T:ty)*) => {
+        $(
+            impl RotateCarry for $T {
+                fn rcl(self, carry: bool) -> ($T, bool) {
+                    (self << 1 | carry as $T, self >> (<$T>::BITS - 1) != 0)
+                }
+
+                fn rcr(self, carry: bool) -> ($T, bool) {
+                    (self >> 1 | (carry as $T) << (<$T>::BITS - 1), self & 1 != 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_rotate_carry!(u8 u16 u32 u64 u128);
+
+impl Bb {
+    /// Rotates `x` left by one bit through an external `carry`, like the x86 `rcl` instruction.
+    ///
+    /// The carry enters at the least significant bit, and the most significant bit becomes the new carry.
+    /// Feeding the carry from one limb into the next shifts a multi-limb number.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// // shift the 128-bit value [lo, hi] left by one
+    /// let (lo, c) = Bb::rcl(0x8000_0000_0000_0001u64, false);
+    /// let (hi, c) = Bb::rcl(0x1u64, c);
+    /// assert_eq!((lo, hi, c), (0x2, 0x3, false));
+    /// ```
+    pub fn rcl<T: RotateCarry>(x: T, carry: bool) -> (T, bool) {
+        x.rcl(carry)
+    }
+
+    /// Rotates `x` right by one bit through an external `carry`, like the x86 `rcr` instruction.
+    ///
+    /// The carry enters at the most significant bit, and the least significant bit becomes the new carry.
+    pub fn rcr<T: RotateCarry>(x: T, carry: bool) -> (T, bool) {
+        x.rcr(carry)
+    }
+
+    /// Rotates the 128-bit value `hi:lo`, kept as two `u64` limbs, left by `n`, producing `(hi, lo)`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::rotl128(0x8000_0000_0000_0000, 1, 1), (0, 0b11));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` isn't less than 128.
+    pub fn rotl128(hi: u64, lo: u64, n: u32) -> (u64, u64) {
+        assert!(n < 128, "rotation exceeds 128 bits");
+        let (hi, lo) = match n < 64 {
+            true => (hi, lo),
+            false => (lo, hi),
+        };
+        match n % 64 {
+            0 => (hi, lo),
+            n => (hi << n | lo >> (64 - n), lo << n | hi >> (64 - n)),
+        }
+    }
+
+    /// Rotates the 128-bit value `hi:lo`, kept as two `u64` limbs, right by `n`, producing `(hi, lo)`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::rotr128(0, 0b11, 1), (0x8000_0000_0000_0000, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` isn't less than 128.
+    pub fn rotr128(hi: u64, lo: u64, n: u32) -> (u64, u64) {
+        assert!(n < 128, "rotation exceeds 128 bits");
+        Bb::rotl128(hi, lo, (128 - n) % 128)
+    }
+
+    /// Rotates the number kept in `words`, least significant word first, left by `n` bits
+    /// modulo its total width, in place.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut words = [1 << 63, 0, 1];
+    /// Bb::rotate_words_left(&mut words, 1);
+    /// assert_eq!(words, [0, 1, 2]);
+    /// ```
+    pub fn rotate_words_left(words: &mut [u64], n: usize) {
+        if words.is_empty() {
+            return;
+        }
+        let n = n % (words.len() * 64);
+        words.rotate_right(n / 64);
+        let bits = n % 64;
+        if bits != 0 {
+            let mut carry = words[words.len() - 1] >> (64 - bits);
+            for w in words.iter_mut() {
+                (*w, carry) = (*w << bits | carry, *w >> (64 - bits));
+            }
+        }
+    }
+
+    /// Rotates the number kept in `words`, least significant word first, right by `n` bits
+    /// modulo its total width, in place.
+    pub fn rotate_words_right(words: &mut [u64], n: usize) {
+        let total = words.len() * 64;
+        if total != 0 {
+            Bb::rotate_words_left(words, total - n % total);
+        }
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_bmi {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for ClearLowest {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    src & src.wrapping_sub(1)
+                }
+            }
+
+            impl AppliedTo<$T> for MaskToLowest {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    src ^ src.wrapping_sub(1)
+                }
+            }
+
+            impl AppliedTo<$T> for CountOnes {
+                type Output = u32;
+
+                fn apply(src: $T) -> u32 {
+                    src.count_ones()
+                }
+            }
+
+            impl AppliedTo<$T> for LeadingRun {
+                type Output = u32;
+
+                fn apply(src: $T) -> u32 {
+                    match src.leading_zeros() {
+                        0 => src.leading_ones(),
+                        n => n,
+                    }
+                }
+            }
+
+            impl AppliedTo<$T> for TrailingRun {
+                type Output = u32;
+
+                fn apply(src: $T) -> u32 {
+                    match src.trailing_zeros() {
+                        0 => src.trailing_ones(),
+                        n => n,
+                    }
+                }
+            }
+
+            impl AppliedTo<$T> for ReverseBits {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    src.reverse_bits()
+                }
+            }
+        )*
+    };
+}
+
+impl_bmi!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+#[doc(hidden)]
+pub trait PartialReverse {
+    fn bit_reverse_n(self, n: u32) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_partial_reverse {
+    ($($T:ty)*) => {
+        $(
+            impl PartialReverse for $T {
+                fn bit_reverse_n(self, n: u32) -> $T {
+                    assert!(n <= <$T>::BITS, "reversed bit count exceeds the bit width");
+                    self.reverse_bits().checked_shr(<$T>::BITS - n).unwrap_or(0)
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_reverse!(u8 u16 u32 u64 u128 usize);
+
+impl Bb {
+    /// Reverses the order of the low `n` bits of `x`, clearing the bits above them.
+    ///
+    /// This is the bit-reversed addressing of radix-2 FFTs over `2^n` elements.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bit_reverse_n(0b001u32, 3), 0b100);
+    /// assert_eq!(Bb::bit_reverse_n(0b110u32, 3), 0b011);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the bit width of `T`.
+    pub fn bit_reverse_n<T: PartialReverse>(x: T, n: u32) -> T {
+        x.bit_reverse_n(n)
+    }
+
+    /// Bit-reversed position of index `i` in a table of `2^n` elements, for FFT reordering.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bitrev_index(1, 3), 4);
+    /// assert_eq!(Bb::bitrev_index(6, 3), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` isn't less than `2^n`.
+    pub fn bitrev_index(i: usize, n: u32) -> usize {
+        assert!(i.checked_shr(n).unwrap_or(0) == 0, "index exceeds the table size");
+        i.bit_reverse_n(n)
+    }
+
+    /// Advances an `n`-bit counter kept in bit-reversed order, carrying from the most significant
+    /// bit downwards, so that `current` steps through the [bitrev_index](Bb::bitrev_index) sequence.
+    /// The counter after all ones wraps to zero.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bitrev_inc(0b000, 3), 0b100);
+    /// assert_eq!(Bb::bitrev_inc(0b100, 3), 0b010);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than 32 or `current` isn't less than `2^n`.
+    pub fn bitrev_inc(current: u32, n: u32) -> u32 {
+        assert!(n <= u32::BITS, "counter width exceeds 32 bits");
+        assert!(current.checked_shr(n).unwrap_or(0) == 0, "counter exceeds its width");
+        let mut bit = 1u32.checked_shl(n.wrapping_sub(1)).unwrap_or(0);
+        let mut current = current;
+        while current & bit != 0 {
+            current ^= bit;
+            bit >>= 1;
+        }
+        current | bit
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct ReverseTruncate(u32);
+
+impl<U> CastSigned<U> {
+    /// Reverses the order of all bits, then keeps the low `n` bits, clearing the rest.
+    /// Reads the top `n` bits of an MSB-first bitstream word, least significant bit first.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(0b1100_0000u8 * Bb.rtrunc(3), 0b011);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the bit width of the type.
+    pub fn rtrunc(&self, n: u32) -> RA<ReverseTruncate, U> {
+        RA(ReverseTruncate(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_rtrunc {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl AppliedWith<$U> for ReverseTruncate {
+                type Output = $U;
+
+                fn apply_with(self, src: $U) -> $U {
+                    assert!(self.0 <= <$U>::BITS, "bit count exceeds the bit width");
+                    src.reverse_bits() & <$U>::MAX.checked_shr(<$U>::BITS - self.0).unwrap_or(0)
+                }
+            }
+
+            impl AppliedWith<$S> for ReverseTruncate {
+                type Output = $S;
+
+                fn apply_with(self, src: $S) -> $S {
+                    self.apply_with(src as $U) as $S
+                }
+            }
+        )*
+    };
+}
+
+impl_rtrunc!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Fence(u32, u32);
+
+impl<U> CastSigned<U> {
+    /// Keeps bits `lo..hi` in place, clearing the rest.
+    /// Unlike extracting a field, the kept bits are not shifted down.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(0xabcdu16 * Bb.fence(4, 12), 0x0bc0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `lo < hi` and `hi` is at most the bit width of the type.
+    pub fn fence(&self, lo: u32, hi: u32) -> RA<Fence, U> {
+        RA(Fence(lo, hi), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_fence {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl AppliedWith<$U> for Fence {
+                type Output = $U;
+
+                fn apply_with(self, src: $U) -> $U {
+                    let Fence(lo, hi) = self;
+                    assert!(lo < hi && hi <= <$U>::BITS, "bit range is empty or exceeds the bit width");
+                    src & <$U>::MAX >> (<$U>::BITS - (hi - lo)) << lo
+                }
+            }
+
+            impl AppliedWith<$S> for Fence {
+                type Output = $S;
+
+                fn apply_with(self, src: $S) -> $S {
+                    self.apply_with(src as $U) as $S
+                }
+            }
+        )*
+    };
+}
+
+impl_fence!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Deposit(u32, u32, u128);
+
+impl<U> CastSigned<U> {
+    /// Writes the low `len` bits of `value` into bits `lo..lo + len`, keeping all other bits.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(0xffffu16 * Bb.deposit(4, 3, 0b101), 0xffdf);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo + len` is greater than the bit width of the type.
+    pub fn deposit(&self, lo: u32, len: u32, value: u128) -> RA<Deposit, U> {
+        RA(Deposit(lo, len, value), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_deposit {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl AppliedWith<$U> for Deposit {
+                type Output = $U;
+
+                fn apply_with(self, src: $U) -> $U {
+                    let Deposit(lo, len, value) = self;
+                    assert!(lo.checked_add(len).is_some_and(|hi| hi <= <$U>::BITS), "field exceeds the bit width");
+                    let mask = <$U>::MAX.checked_shr(<$U>::BITS - len).unwrap_or(0) << (lo % <$U>::BITS);
+                    src & !mask | (value as $U) << (lo % <$U>::BITS) & mask
+                }
+            }
+
+            impl AppliedWith<$S> for Deposit {
+                type Output = $S;
+
+                fn apply_with(self, src: $S) -> $S {
+                    self.apply_with(src as $U) as $S
+                }
+            }
+        )*
+    };
+}
+
+impl_deposit!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+#[doc(hidden)]
+macro_rules! impl_norm_msb {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for NormMsb {
+                type Output = ($T, u32);
+
+                fn apply(src: $T) -> ($T, u32) {
+                    match src {
+                        0 => (0, 0),
+                        src => (src << src.leading_zeros(), src.leading_zeros()),
+                    }
+                }
+            }
+
+            impl Denormalize for $T {
+                fn denorm_msb(self, shift: u32) -> $T {
+                    self >> shift
+                }
+            }
+        )*
+    };
+}
+
+impl_norm_msb!(u8 u16 u32 u64 u128);
+
+#[doc(hidden)]
+pub trait Denormalize {
+    fn denorm_msb(self, shift: u32) -> Self;
+}
+
+impl Bb {
+    /// Undoes `Bb.norm_msb`, shifting a left-justified value back right by `shift`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let (v, shift) = 0b101u8 * Bb.norm_msb;
+    /// assert_eq!((v, shift), (0b1010_0000, 5));
+    /// assert_eq!(Bb::denorm_msb(v, shift), 0b101);
+    /// ```
+    pub fn denorm_msb<T: Denormalize>(value: T, shift: u32) -> T {
+        value.denorm_msb(shift)
+    }
+}
+
+#[doc(hidden)]
+pub trait PopLowest {
+    fn pop_lowest(&mut self) -> Option<u32>;
+
+    fn count_set(&self) -> u32;
+}
+
+#[doc(hidden)]
+macro_rules! impl_pop_lowest {
+    ($($T:ty)*) => {
+        $(
+            impl PopLowest for $T {
+                fn pop_lowest(&mut self) -> Option<u32> {
+                    if *self == 0 {
+                        return None;
+                    }
+                    let i = self.trailing_zeros();
+                    *self = *self * Bb.blsr;
+                    Some(i)
+                }
+
+                fn count_set(&self) -> u32 {
+                    self.count_ones()
+                }
+            }
+        )*
+    };
+}
+
+impl_pop_lowest!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+/// Iterator over the indices of set bits, from least to most significant. See [Bb::bit_iter].
+#[derive(Clone, Copy, Debug)]
+pub struct BitIter<T>(T);
+
+impl<T: PopLowest> Iterator for BitIter<T> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0.pop_lowest()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.0.count_set() as usize;
+        (n, Some(n))
+    }
+}
+
+impl<T: PopLowest> ExactSizeIterator for BitIter<T> {}
+
+impl<T: PopLowest> FusedIterator for BitIter<T> {}
+
+impl Bb {
+    /// Lazily iterates over the indices of set bits of `x`, from least to most significant.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert!(Bb::bit_iter(0b1010u8).eq([1, 3]));
+    /// ```
+    pub fn bit_iter<T: PopLowest>(x: T) -> BitIter<T> {
+        BitIter(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pext_ref(x: u64, mask: u64) -> u64 {
+        let mut res = 0;
+        let mut k = 0;
+        for i in 0..64 {
+            if mask >> i & 1 == 1 {
+                res |= (x >> i & 1) << k;
+                k += 1;
+            }
+        }
+        res
+    }
+
+    fn pdep_ref(x: u64, mask: u64) -> u64 {
+        let mut res = 0;
+        let mut k = 0;
+        for i in 0..64 {
+            if mask >> i & 1 == 1 {
+                res |= (x >> k & 1) << i;
+                k += 1;
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn pext_pdep() {
+        let values = [0, 1, u64::MAX, 0xdead_beef_cafe_babe, 0x8000_0000_0000_0001, 0x0123_4567_89ab_cdef];
+        for x in values {
+            for m in values {
+                assert_eq!(Bb::pext(x, m), pext_ref(x, m));
+                assert_eq!(Bb::pdep(x, m), pdep_ref(x, m));
+                assert_eq!(Bb::pdep(Bb::pext(x, m), m), x & m);
+            }
+            assert_eq!(Bb::pext(x, 0), 0);
+            assert_eq!(Bb::pdep(x, 0), 0);
+        }
+        assert_eq!(Bb::pext(u128::MAX, u128::MAX), u128::MAX);
+        assert_eq!(Bb::pdep(u128::MAX, 1 << 127), 1 << 127);
+        assert_eq!(Bb::pext(-1i8, 0x70), 0b111);
+        assert_eq!(Bb::pdep(-1i16, i16::MIN), i16::MIN);
+        assert_eq!(Bb::pext(0xabu8, 0xff), 0xab);
+    }
+
+    #[test]
+    fn rev() {
+        assert_eq!(1u8 * Bb.rev, 0x80);
+        assert_eq!(0x0000_0001_0000_0003u64 * Bb.rev, 0xc000_0000_8000_0000);
+        assert_eq!(1i16 * Bb.rev, i16::MIN);
+        assert_eq!(0x1234_5678u32 * Bb.rev.rev, 0x1234_5678);
+    }
+
+    #[test]
+    fn bit_counts() {
+        assert_eq!(Bb::bit_counts::<u32>(&[]), [0; 32]);
+        let counts = Bb::bit_counts(&[u32::MAX, 1, 0x8000_0001, 0x10]);
+        let mut expected = [1; 32];
+        expected[0] = 3;
+        expected[4] = 2;
+        expected[31] = 2;
+        assert_eq!(counts, expected);
+        let xs: [u16; 100] = core::array::from_fn(|i| i as u16);
+        let counts = Bb::bit_counts(&xs);
+        for (bit, &c) in counts.iter().enumerate() {
+            assert_eq!(c, xs.iter().filter(|&&x| x >> bit & 1 == 1).count() as u32);
+        }
+        assert_eq!(Bb::bit_counts(&[1u128 << 127; 3])[127], 3);
+        assert_eq!(Bb::bit_counts(&[0xffu8; 7]), [7; 8]);
+    }
+
+    #[test]
+    fn collapse_runs() {
+        assert_eq!(Bb::collapse_runs(0u16), (0, 1));
+        assert_eq!(Bb::collapse_runs(u16::MAX), (1, 1));
+        assert_eq!(Bb::collapse_runs(0x5555u16), (0x5555, 16));
+        assert_eq!(Bb::collapse_runs(0xaaaa_aaaau32), (0xaaaa_aaaa, 32));
+        assert_eq!(Bb::collapse_runs(0xff00u16), (0b10, 2));
+        assert_eq!(Bb::collapse_runs(0x00ffu16), (0b01, 2));
+        assert_eq!(Bb::collapse_runs(0x0ff0_f00fu32), (0b010101, 6));
+        assert_eq!(Bb::collapse_runs(1u64 << 63), (0b10, 2));
+        assert_eq!(Bb::collapse_runs(u128::MAX >> 1), (0b01, 2));
+        for x in (0..=u16::MAX).step_by(131) {
+            let (mut runs, mut prev) = (1, x & 1);
+            for i in 1..16 {
+                let bit = x >> i & 1;
+                runs += (bit != prev) as u32;
+                prev = bit;
+            }
+            let (value, count) = Bb::collapse_runs(x);
+            assert_eq!(count, runs);
+            assert_eq!(value & 1, x & 1);
+            assert_eq!(value >> (count - 1), x >> 15);
+        }
+    }
+
+    #[test]
+    fn spread_bits() {
+        for x in [0u16, 1, 0x8000, 0xffff, 0xa5c3, 0x1234] {
+            assert_eq!(Bb::spread_bits(x, 1), x as u64);
+            assert_eq!(Bb::spread_bits(x, 2), Bb::pdep(x as u64, 0x5555_5555));
+            assert_eq!(Bb::spread_bits(x, 3), Bb::pdep(x as u64, 0x9249_2492_4924_9249));
+            assert_eq!(Bb::spread_bits(x, 4), Bb::pdep(x as u64, 0x1111_1111_1111_1111));
+        }
+        let x = 0xdead_beefu32;
+        assert_eq!(Bb::spread_bits(x, 2), Bb::pdep(x as u128, u128::MAX / 3));
+        assert_eq!(Bb::spread_bits(x, 3), Bb::pdep(x as u128, (u128::MAX >> 2) / 7));
+        assert_eq!(Bb::spread_bits(u32::MAX, 3), ((1 << 96) - 1) / 7);
+        assert_eq!(Bb::spread_bits(0x80u8, 4), 1 << 28);
+        // interleaving three spread coordinates gives the 3D Morton code
+        let (x, y, z) = (0b11u16, 0b01u16, 0b10u16);
+        let code = Bb::spread_bits(x, 3) | Bb::spread_bits(y, 3) << 1 | Bb::spread_bits(z, 3) << 2;
+        assert_eq!(code, 0b101_011);
+    }
+
+    #[test]
+    #[should_panic = "stride must be in 1..=4"]
+    fn spread_bits_stride() {
+        let _ = Bb::spread_bits(1u8, 5);
+    }
+
+    #[test]
+    fn morton3() {
+        assert_eq!(Bb::morton3(0, 0, 0), 0);
+        assert_eq!(Bb::morton3(1, 0, 0), 1);
+        assert_eq!(Bb::morton3(0, 1, 0), 2);
+        assert_eq!(Bb::morton3(0, 0, 1), 4);
+        assert_eq!(Bb::morton3(0x3ff, 0x3ff, 0x3ff), (1 << 30) - 1);
+        // bits above the low 10 are ignored
+        assert_eq!(Bb::morton3(0xfc00, 0x400, 0xffff), Bb::morton3(0, 0, 0x3ff));
+        for (x, y, z) in [(0x155, 0x2aa, 0x3ff), (1, 0x200, 0x123), (0x3ff, 0, 0x0f0)] {
+            let code = Bb::morton3(x, y, z);
+            let spread = |c: u16| Bb::spread_bits(c, 3) as u32;
+            assert_eq!(code, spread(x) | spread(y) << 1 | spread(z) << 2);
+            assert_eq!(Bb::demorton3(code), (x, y, z));
+        }
+        for code in (0..1 << 30).step_by(9973) {
+            let (x, y, z) = Bb::demorton3(code);
+            assert_eq!(Bb::morton3(x, y, z), code);
+        }
+        assert_eq!(Bb::demorton3(u32::MAX), (0x3ff, 0x3ff, 0x3ff));
+    }
+
+    #[test]
+    fn runs() {
+        assert_eq!(0u8 * Bb.lrun, 8);
+        assert_eq!(0u8 * Bb.trun, 8);
+        assert_eq!(u32::MAX * Bb.lrun, 32);
+        assert_eq!(-1i64 * Bb.trun, 64);
+        assert_eq!(0b1110_0100u8 * Bb.lrun, 3);
+        assert_eq!(0b1110_0100u8 * Bb.trun, 2);
+        assert_eq!(0b0001_0111u8 * Bb.lrun, 3);
+        assert_eq!(0b0001_0111u8 * Bb.trun, 3);
+        assert_eq!(1u16 * Bb.lrun, 15);
+        assert_eq!(1u16 * Bb.trun, 1);
+        assert_eq!(i32::MIN * Bb.lrun, 1);
+        assert_eq!(i32::MIN * Bb.trun, 31);
+        assert_eq!(-2i128 * Bb.lrun, 127);
+        assert_eq!(0xff00u16 * Bb.t8.lrun, 8);
+    }
+
+    #[test]
+    fn ones() {
+        assert_eq!(0u8 * Bb.ones, 0);
+        assert_eq!(-1i8 * Bb.ones, 8);
+        assert_eq!(0xf0f0u16 * Bb.ones, 8);
+        assert_eq!(i64::MIN * Bb.ones, 1);
+        assert_eq!(u128::MAX * Bb.ones, 128);
+        assert_eq!(-1i128 * Bb.ones, 128);
+        assert_eq!((1u128 << 127 | 1) * Bb.ones, 2);
+        // xorshift, checked against counting 64-bit halves separately
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..1000 {
+            let (hi, lo) = (next(), next());
+            let x = (hi as u128) << 64 | lo as u128;
+            assert_eq!(x * Bb.ones, hi.count_ones() + lo.count_ones());
+            assert_eq!(x * Bb.s.ones, x * Bb.ones);
+        }
+    }
+
+    #[test]
+    fn blsr_blsmsk() {
+        assert_eq!(0u32 * Bb.blsr, 0);
+        assert_eq!(0u32 * Bb.blsmsk, u32::MAX);
+        assert_eq!(0i8 * Bb.blsmsk, -1);
+        assert_eq!(i8::MIN * Bb.blsr, 0);
+        assert_eq!(i8::MIN * Bb.blsmsk, -1);
+        assert_eq!(0b1011_0100u8 * Bb.blsr, 0b1011_0000);
+        assert_eq!(0b1011_0100u8 * Bb.blsmsk, 0b0000_0111);
+        for i in 0..64 {
+            let bit = 1u64 << i;
+            assert_eq!(bit * Bb.blsr, 0);
+            assert_eq!(bit * Bb.blsmsk, u64::MAX >> (63 - i));
+            assert_eq!((bit | 1 << 63) * Bb.blsr, if i == 63 { 0 } else { 1 << 63 });
+        }
+    }
+
+    fn clmul_ref(a: u64, b: u64) -> u128 {
+        let mut res = 0;
+        for i in 0..64 {
+            if b >> i & 1 == 1 {
+                res ^= (a as u128) << i;
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn clmul() {
+        assert_eq!(Bb::clmul(0xffu8, 0xff), 0x5555);
+        assert_eq!(Bb::clmul(u64::MAX, u64::MAX), u128::MAX / 3);
+        assert_eq!(Bb::clmul(0x8000u16, 0x8000), 0x4000_0000);
+        assert_eq!(Bb::clmul(0x1234_5678u32, 0), 0);
+        let values = [1, 3, 0xdead_beef_cafe_babe, 0x8000_0000_0000_0001, 0x0123_4567_89ab_cdef];
+        for a in values {
+            for b in values {
+                assert_eq!(Bb::clmul(a, b), clmul_ref(a, b));
+                assert_eq!(Bb::clmul(a as u32, b as u32) as u128, clmul_ref(a as u32 as u64, b as u32 as u64));
+            }
+        }
+    }
+
+    #[test]
+    fn bit_reverse_n() {
+        fn manual(x: u32, n: u32) -> u32 {
+            (0..n).fold(0, |res, i| res << 1 | x >> i & 1)
+        }
+
+        for x in 0..8 {
+            assert_eq!(Bb::bit_reverse_n(x, 3), manual(x, 3));
+        }
+        for x in (0..1024).step_by(7) {
+            assert_eq!(Bb::bit_reverse_n(x, 10), manual(x, 10));
+            assert_eq!(Bb::bit_reverse_n(Bb::bit_reverse_n(x, 10), 10), x);
+        }
+        assert_eq!(Bb::bit_reverse_n(1u32, 10), 0x200);
+        assert_eq!(Bb::bit_reverse_n(0x7ffu32, 10), 0x3ff);
+        assert_eq!(Bb::bit_reverse_n(u8::MAX, 0), 0);
+        assert_eq!(Bb::bit_reverse_n(1u64, 64), 1 << 63);
+    }
+
+    #[test]
+    fn bitrev_index() {
+        let perm: [usize; 8] = core::array::from_fn(|i| Bb::bitrev_index(i, 3));
+        assert_eq!(perm, [0, 4, 2, 6, 1, 5, 3, 7]);
+        let perm: [usize; 16] = core::array::from_fn(|i| Bb::bitrev_index(i, 4));
+        assert_eq!(perm, [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15]);
+        for (i, &j) in perm.iter().enumerate() {
+            assert_eq!(perm[j], i);
+        }
+        assert_eq!(Bb::bitrev_index(0, 0), 0);
+        assert_eq!(Bb::bitrev_index(1, usize::BITS), 1 << (usize::BITS - 1));
+    }
+
+    #[test]
+    fn rtrunc() {
+        for x in [0u32, 1, 0x8000_0000, 0xdead_beef, u32::MAX, 0x1234_5678] {
+            for n in 0..=32 {
+                let mask = u32::MAX.checked_shr(32 - n).unwrap_or(0);
+                assert_eq!(x * Bb.rtrunc(n), x.reverse_bits() & mask);
+            }
+        }
+        assert_eq!(0x8000u16 * Bb.rtrunc(1), 1);
+        assert_eq!(0x8000u16 * Bb.rtrunc(0), 0);
+        assert_eq!(0xa000_0000u32 * Bb.rtrunc(4), 0b0101);
+        assert_eq!(i8::MIN * Bb.rtrunc(8), 1);
+        assert_eq!(-1i64 * Bb.rtrunc(64), -1);
+        assert_eq!(1u128 * Bb.rtrunc(128), 1 << 127);
+    }
+
+    #[test]
+    #[should_panic = "bit count exceeds the bit width"]
+    fn rtrunc_overlong() {
+        let _ = 0u8 * Bb.rtrunc(9);
+    }
+
+    #[test]
+    fn bitrev_inc() {
+        let mut x = 0;
+        let mut seq = [0; 8];
+        for s in &mut seq {
+            *s = x;
+            x = Bb::bitrev_inc(x, 3);
+        }
+        assert_eq!(seq, [0, 4, 2, 6, 1, 5, 3, 7]);
+        assert_eq!(x, 0);
+        let mut x = 0;
+        for i in 0..1 << 10 {
+            assert_eq!(x as usize, Bb::bitrev_index(i, 10));
+            x = Bb::bitrev_inc(x, 10);
+        }
+        assert_eq!(x, 0);
+        assert_eq!(Bb::bitrev_inc(0, 0), 0);
+        assert_eq!(Bb::bitrev_inc(0, 1), 1);
+        assert_eq!(Bb::bitrev_inc(1, 1), 0);
+        assert_eq!(Bb::bitrev_inc(0, 32), 1 << 31);
+        assert_eq!(Bb::bitrev_inc(u32::MAX, 32), 0);
+    }
+
+    #[test]
+    #[should_panic = "counter exceeds its width"]
+    fn bitrev_inc_out_of_range() {
+        let _ = Bb::bitrev_inc(8, 3);
+    }
+
+    #[test]
+    #[should_panic = "index exceeds the table size"]
+    fn bitrev_index_out_of_range() {
+        let _ = Bb::bitrev_index(8, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_reverse_n_overlong() {
+        let _ = Bb::bit_reverse_n(0u16, 17);
+    }
+
+    #[test]
+    fn norm_msb() {
+        assert_eq!(0u32 * Bb.norm_msb, (0, 0));
+        assert_eq!(1u32 * Bb.norm_msb, (0x8000_0000, 31));
+        assert_eq!(0x80u8 * Bb.norm_msb, (0x80, 0));
+        assert_eq!(0x0123u16 * Bb.norm_msb, (0x9180, 7));
+        assert_eq!(3u128 * Bb.norm_msb, (3 << 126, 126));
+        for x in [1u64, 0x5, 0xdead, 0x8000_0000_0000_0000, u64::MAX] {
+            let (v, shift) = x * Bb.norm_msb;
+            assert_eq!(v.leading_zeros(), 0);
+            assert_eq!(Bb::denorm_msb(v, shift), x);
+        }
+        assert_eq!(Bb::denorm_msb(0u16, 0), 0);
+    }
+
+    #[test]
+    fn bit_scan() {
+        assert_eq!(Bb::bsr(0u32), None);
+        assert_eq!(Bb::bsf(0u32), None);
+        for i in 0..64 {
+            assert_eq!(Bb::bsr(1u64 << i), Some(i));
+            assert_eq!(Bb::bsf(1u64 << i), Some(i));
+        }
+        assert_eq!(Bb::bsr(0x0ff0u16), Some(11));
+        assert_eq!(Bb::bsf(0x0ff0u16), Some(4));
+        assert_eq!(Bb::bsr(-1i8), Some(7));
+        assert_eq!(Bb::bsf(i128::MIN), Some(127));
+        assert_eq!(Bb::bsr(u128::MAX), Some(127));
+        assert_eq!(Bb::bsf(u128::MAX), Some(0));
+    }
+
+    #[test]
+    fn hamming() {
+        assert_eq!(Bb::hamming(0x5au8, 0x5a), 0);
+        assert_eq!(Bb::hamming(-7i64, -7), 0);
+        for i in 0..32 {
+            assert_eq!(Bb::hamming(0x1234_5678u32, 0x1234_5678 ^ 1 << i), 1);
+        }
+        assert_eq!(Bb::hamming(0x1234u16, !0x1234), 16);
+        assert_eq!(Bb::hamming(0i128, -1), 128);
+        assert_eq!(Bb::hamming(u64::MAX, 0), 64);
+    }
+
+    #[test]
+    fn common_prefix_len() {
+        assert_eq!(Bb::common_prefix_len(0x5au8, 0x5a), 8);
+        assert_eq!(Bb::common_prefix_len(-7i64, -7), 64);
+        assert_eq!(Bb::common_prefix_len(usize::MAX, usize::MAX), usize::BITS);
+        assert_eq!(Bb::common_prefix_len(0x1234_5678u32, 0x1234_5679), 31);
+        assert_eq!(Bb::common_prefix_len(0x1234_5678u32, 0x1235_0000), 15);
+        assert_eq!(Bb::common_prefix_len(0x8000u16, 0x7fff), 0);
+        assert_eq!(Bb::common_prefix_len(-1i32, 0), 0);
+        assert_eq!(Bb::common_prefix_len(1u128, 0), 127);
+    }
+
+    #[test]
+    fn mask() {
+        assert_eq!(Bb::mask::<u32>(0), 0);
+        assert_eq!(Bb::mask::<u32>(1), 1);
+        assert_eq!(Bb::mask::<u32>(13), 0x1fff);
+        assert_eq!(Bb::mask::<u32>(31), 0x7fff_ffff);
+        assert_eq!(Bb::mask::<u32>(32), u32::MAX);
+        assert_eq!(Bb::mask::<u64>(0), 0);
+        assert_eq!(Bb::mask::<u64>(33), 0x1_ffff_ffff);
+        assert_eq!(Bb::mask::<u64>(63), u64::MAX >> 1);
+        assert_eq!(Bb::mask::<u64>(64), u64::MAX);
+        assert_eq!(Bb::mask::<i64>(64), -1);
+        assert_eq!(Bb::mask::<i64>(63), i64::MAX);
+        assert_eq!(Bb::mask::<u128>(128), u128::MAX);
+        assert_eq!(Bb::mask::<i8>(4), 0xf);
+    }
+
+    #[test]
+    fn replicate_bit() {
+        assert_eq!(Bb::replicate_bit::<u32>(true, 12), 0xfff);
+        assert_eq!(Bb::replicate_bit::<u32>(false, 12), 0);
+        assert_eq!(Bb::replicate_bit::<u32>(true, 0), 0);
+        assert_eq!(Bb::replicate_bit::<u32>(true, 32), u32::MAX);
+        assert_eq!(Bb::replicate_bit::<u32>(false, 32), 0);
+        assert_eq!(Bb::replicate_bit::<i8>(true, 8), -1);
+        assert_eq!(Bb::replicate_bit::<i8>(true, 7), i8::MAX);
+        assert_eq!(Bb::replicate_bit::<u128>(true, 128), u128::MAX);
+        assert_eq!(Bb::replicate_bit::<u64>(false, 64), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mask_overlong() {
+        let _ = Bb::mask::<u32>(33);
+    }
+
+    #[test]
+    fn select() {
+        assert_eq!(Bb::select(0x1234u16, 0xabcd, 0x0ff0), 0x1bc4);
+        assert_eq!(Bb::select(0x1234u16, 0xabcd, 0), 0x1234);
+        assert_eq!(Bb::select(0x1234u16, 0xabcd, u16::MAX), 0xabcd);
+        assert_eq!(Bb::select(0u8, u8::MAX, 0b1010_0101), 0b1010_0101);
+        assert_eq!(Bb::select(-1i32, 0, 0xff), -0x100);
+        assert_eq!(Bb::select(0i128, -1, i128::MIN), i128::MIN);
+    }
+
+    #[test]
+    fn rotate128() {
+        let limbs = |x: u128| ((x >> 64) as u64, x as u64);
+        for x in [0u128, 1, u128::MAX, 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210, 1 << 63, 1 << 64] {
+            let (hi, lo) = limbs(x);
+            for n in 0..128 {
+                assert_eq!(Bb::rotl128(hi, lo, n), limbs(x.rotate_left(n)));
+                assert_eq!(Bb::rotr128(hi, lo, n), limbs(x.rotate_right(n)));
+            }
+        }
+        assert_eq!(Bb::rotl128(1, 2, 0), (1, 2));
+        assert_eq!(Bb::rotl128(1, 2, 64), (2, 1));
+        assert_eq!(Bb::rotr128(1, 2, 64), (2, 1));
+    }
+
+    #[test]
+    fn rotate_words() {
+        let x = [0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210, 0x0f1e_2d3c_4b5a_6978, 0x8000_0000_0000_0001u64];
+        let mut words = x;
+        Bb::rotate_words_left(&mut words, 1);
+        assert_eq!(words, [0x0246_8acf_1357_9bdf, 0xfdb9_7530_eca8_6420, 0x1e3c_5a78_96b4_d2f1, 2]);
+        Bb::rotate_words_right(&mut words, 1);
+        assert_eq!(words, x);
+        Bb::rotate_words_left(&mut words, 64);
+        assert_eq!(words, [x[3], x[0], x[1], x[2]]);
+        Bb::rotate_words_right(&mut words, 64);
+        assert_eq!(words, x);
+        Bb::rotate_words_left(&mut words, 70);
+        let mut two_step = x;
+        Bb::rotate_words_left(&mut two_step, 64);
+        Bb::rotate_words_left(&mut two_step, 6);
+        assert_eq!(words, two_step);
+        Bb::rotate_words_right(&mut words, 70);
+        assert_eq!(words, x);
+        Bb::rotate_words_left(&mut words, 256);
+        assert_eq!(words, x);
+        Bb::rotate_words_left(&mut words, 256 + 3);
+        Bb::rotate_words_right(&mut words, 3);
+        assert_eq!(words, x);
+        // two words match the native 128-bit rotation
+        let y = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        for n in [0, 1, 63, 64, 65, 100, 127] {
+            let mut words = [y as u64, (y >> 64) as u64];
+            Bb::rotate_words_left(&mut words, n);
+            let r = y.rotate_left(n as u32);
+            assert_eq!(words, [r as u64, (r >> 64) as u64]);
+        }
+        Bb::rotate_words_left(&mut [], 5);
+        Bb::rotate_words_right(&mut [], 5);
+    }
+
+    #[test]
+    fn fence() {
+        let x = 0x1234_5678u32;
+        assert_eq!(x * Bb.fence(8, 24), 0x0034_5600);
+        assert_eq!(x * Bb.fence(8, 24), x & (0xffff << 8));
+        assert_eq!((x * Bb.fence(8, 24)) >> 8, (x >> 8) & 0xffff);
+        assert_eq!(x * Bb.fence(0, 32), x);
+        assert_eq!(x * Bb.fence(31, 32), 0);
+        assert_eq!(u128::MAX * Bb.fence(127, 128), 1 << 127);
+        assert_eq!(-1i8 * Bb.fence(6, 8), -64);
+        assert_eq!(-1i64 * Bb.fence(1, 3), 0b110);
+    }
+
+    #[test]
+    fn deposit() {
+        let x = 0x1234_5678u32;
+        assert_eq!(x * Bb.deposit(8, 8, 0xab), 0x1234_ab78);
+        assert_eq!(x * Bb.deposit(8, 8, 0xfab), 0x1234_ab78);
+        assert_eq!((x * Bb.deposit(8, 8, 0xab)) & !0xff00, x & !0xff00);
+        assert_eq!((x * Bb.deposit(8, 8, 0xab)) * Bb.fence(8, 16), 0xab00);
+        assert_eq!(0u16 * Bb.deposit(4, 3, 0b101), 0b0101_0000);
+        assert_eq!(0xffffu16 * Bb.deposit(4, 3, 0), 0xff8f);
+        assert_eq!(x * Bb.deposit(0, 32, 7), 7);
+        assert_eq!(x * Bb.deposit(3, 0, u128::MAX), x);
+        assert_eq!(x * Bb.deposit(32, 0, 1), x);
+        assert_eq!(0u128 * Bb.deposit(127, 1, 1), 1 << 127);
+        assert_eq!(0i8 * Bb.deposit(7, 1, 1), i8::MIN);
+        assert_eq!(-1i32 * Bb.deposit(0, 4, 0), -16);
+    }
+
+    #[test]
+    #[should_panic = "field exceeds the bit width"]
+    fn deposit_overlong() {
+        let _ = 0u8 * Bb.deposit(6, 3, 0);
+    }
+
+    #[test]
+    #[should_panic = "bit range is empty or exceeds the bit width"]
+    fn fence_empty() {
+        let _ = 0u8 * Bb.fence(3, 3);
+    }
+
+    #[test]
+    #[should_panic = "bit range is empty or exceeds the bit width"]
+    fn fence_overlong() {
+        let _ = 0u16 * Bb.fence(8, 17);
+    }
+
+    #[test]
+    #[should_panic = "rotation exceeds 128 bits"]
+    fn rotl128_overlong() {
+        let _ = Bb::rotl128(0, 0, 128);
+    }
+
+    #[test]
+    fn rotate_carry() {
+        assert_eq!(Bb::rcl(0x80u8, false), (0x00, true));
+        assert_eq!(Bb::rcl(0x40u8, true), (0x81, false));
+        assert_eq!(Bb::rcr(0x01u8, false), (0x00, true));
+        assert_eq!(Bb::rcr(0x02u8, true), (0x81, false));
+        assert_eq!(Bb::rcl(u128::MAX, false), (u128::MAX - 1, true));
+        // a full rotation through the carry restores both
+        let (mut x, mut c) = (0xa5u8, true);
+        for _ in 0..9 {
+            (x, c) = Bb::rcl(x, c);
+        }
+        assert_eq!((x, c), (0xa5, true));
+
+        let wide = 0x8123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        let (lo, c) = Bb::rcl(wide as u64, false);
+        let (hi, c) = Bb::rcl((wide >> 64) as u64, c);
+        assert_eq!((hi as u128) << 64 | lo as u128, wide << 1);
+        assert!(c);
+        let (hi, c) = Bb::rcr((wide >> 64) as u64, false);
+        let (lo, c) = Bb::rcr(wide as u64, c);
+        assert_eq!((hi as u128) << 64 | lo as u128, wide >> 1);
+        assert!(!c);
+    }
+
+    #[test]
+    fn bit_iter() {
+        assert!(Bb::bit_iter(0b1010u8).eq([1, 3]));
+        assert_eq!(Bb::bit_iter(0u64).next(), None);
+        assert!(Bb::bit_iter(u32::MAX).eq(0..32));
+        assert!(Bb::bit_iter(-1i128).eq(0..128));
+        assert!(Bb::bit_iter(i16::MIN | 1).eq([0, 15]));
+        let mut it = Bb::bit_iter(0x8000_0000_0000_0101u64);
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.next(), Some(8));
+        assert_eq!(it.next(), Some(63));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+}
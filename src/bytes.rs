@@ -0,0 +1,618 @@
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+use crate::AppliedWith;
+use crate::Bb;
+use crate::CastSigned;
+use crate::RA;
+
+impl Bb {
+    /// Reinterprets `N` bytes as a `T` of the same size. Mismatched sizes fail to compile.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let bytes = MaybeUninit::new(1u32.to_ne_bytes());
+    /// assert_eq!(unsafe { Bb::assume_init_cast::<4, u32>(bytes) }, 1);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use yabe::Bb;
+    /// # use core::mem::MaybeUninit;
+    /// let _ = unsafe { Bb::assume_init_cast::<2, u32>(MaybeUninit::new([0; 2])) };
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// All `N` bytes must be initialized, and together they must form a valid `T`.
+    /// Any initialized bytes are a valid integer.
+    pub const unsafe fn assume_init_cast<const N: usize, T>(bytes: MaybeUninit<[u8; N]>) -> T {
+        const { assert!(core::mem::size_of::<T>() == N, "size of T doesn't match byte count") };
+        unsafe { bytes.as_ptr().cast::<T>().read_unaligned() }
+    }
+}
+
+#[doc(hidden)]
+pub trait FindByte {
+    /// Word with the high bit set in the lowest byte equal to `b`, and possibly in higher bytes.
+    fn byte_flags(self, b: u8) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_find_byte {
+    ($($T:ty)*) => {
+        $(
+            impl FindByte for $T {
+                fn byte_flags(self, b: u8) -> $T {
+                    const LO: $T = <$T>::MAX / 0xff;
+                    const HI: $T = LO << 7;
+                    let x = self ^ LO * b as $T;
+                    x.wrapping_sub(LO) & !x & HI
+                }
+            }
+        )*
+    };
+}
+
+impl_find_byte!(u32 u64);
+
+impl Bb {
+    /// Tells whether any byte of `word` equals `b`, without branching on individual bytes.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert!(Bb::has_byte(u64::from_le_bytes(*b"key=val\0"), b'='));
+    /// ```
+    pub fn has_byte<T: FindByte + Default + PartialEq>(word: T, b: u8) -> bool {
+        word.byte_flags(b) != T::default()
+    }
+
+    /// Index of the least significant byte of `word` equal to `b`.
+    ///
+    /// Bytes are indexed by significance, not memory order: the least significant byte has index 0.
+    /// Use `from_le_bytes` to load words whose indices should match memory order.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::first_byte_index(u64::from_le_bytes(*b"key=val\0"), b'='), Some(3));
+    /// assert_eq!(Bb::first_byte_index(0x1122_3344u32, 0x11), Some(3));
+    /// ```
+    pub fn first_byte_index<T: FindByte + Default + PartialEq + Into<u64>>(word: T, b: u8) -> Option<usize> {
+        let flags: u64 = word.byte_flags(b).into();
+        match flags {
+            0 => None,
+            flags => Some(flags.trailing_zeros() as usize / 8),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait RotateBytes {
+    fn rotate_bytes_left(self, n: u32) -> Self;
+
+    fn rotate_bytes_right(self, n: u32) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_rotate_bytes {
+    ($($T:ty)*) => {
+        $(
+            impl RotateBytes for $T {
+                fn rotate_bytes_left(self, n: u32) -> $T {
+                    self.rotate_left(n % (<$T>::BITS / 8) * 8)
+                }
+
+                fn rotate_bytes_right(self, n: u32) -> $T {
+                    self.rotate_right(n % (<$T>::BITS / 8) * 8)
+                }
+            }
+        )*
+    };
+}
+
+impl_rotate_bytes!(u16 u32 u64 u128);
+
+impl Bb {
+    /// Rotates `x` left by `n` whole bytes, modulo the size of `x`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::rotate_bytes_left(0x1122_3344u32, 1), 0x2233_4411);
+    /// ```
+    pub fn rotate_bytes_left<T: RotateBytes>(x: T, n: u32) -> T {
+        x.rotate_bytes_left(n)
+    }
+
+    /// Rotates `x` right by `n` whole bytes, modulo the size of `x`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::rotate_bytes_right(0x1122_3344u32, 1), 0x4411_2233);
+    /// ```
+    pub fn rotate_bytes_right<T: RotateBytes>(x: T, n: u32) -> T {
+        x.rotate_bytes_right(n)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct RotateBytesIn<const LANE: usize>(u32);
+
+impl<U> CastSigned<U> {
+    /// Rotates the bytes within each `LANE`-byte lane left by `n`, modulo `LANE`, independently of other lanes.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(0x1122_3344_5566_7788u64 * Bb.rotbytes_in::<4>(1), 0x2233_4411_6677_8855);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LANE` is zero or doesn't divide the size of the type.
+    pub fn rotbytes_in<const LANE: usize>(&self, n: u32) -> RA<RotateBytesIn<LANE>, U> {
+        RA(RotateBytesIn(n), PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_rotbytes_in {
+    ($($T:ty)*) => {
+        $(
+            impl<const LANE: usize> AppliedWith<$T> for RotateBytesIn<LANE> {
+                type Output = $T;
+
+                fn apply_with(self, src: $T) -> $T {
+                    assert!(LANE != 0 && size_of::<$T>() % LANE == 0, "lane size doesn't divide the width");
+                    let mut bytes = src.to_le_bytes();
+                    for lane in bytes.chunks_exact_mut(LANE) {
+                        lane.rotate_right(self.0 as usize % LANE);
+                    }
+                    <$T>::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_rotbytes_in!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+#[doc(hidden)]
+pub trait InterleaveBytes: Sized {
+    type Wide;
+
+    fn interleave(a: Self, b: Self) -> Self::Wide;
+
+    fn deinterleave(w: Self::Wide) -> (Self, Self);
+}
+
+#[doc(hidden)]
+macro_rules! impl_interleave_bytes {
+    ($($T:ty => $W:ty),*) => {
+        $(
+            impl InterleaveBytes for $T {
+                type Wide = $W;
+
+                fn interleave(a: $T, b: $T) -> $W {
+                    let (a, b) = (a.to_le_bytes(), b.to_le_bytes());
+                    let mut w = [0; size_of::<$W>()];
+                    for i in 0..a.len() {
+                        w[2 * i] = a[i];
+                        w[2 * i + 1] = b[i];
+                    }
+                    <$W>::from_le_bytes(w)
+                }
+
+                fn deinterleave(w: $W) -> ($T, $T) {
+                    let w = w.to_le_bytes();
+                    let (mut a, mut b) = ([0; size_of::<$T>()], [0; size_of::<$T>()]);
+                    for i in 0..a.len() {
+                        a[i] = w[2 * i];
+                        b[i] = w[2 * i + 1];
+                    }
+                    (<$T>::from_le_bytes(a), <$T>::from_le_bytes(b))
+                }
+            }
+        )*
+    };
+}
+
+impl_interleave_bytes!(u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+
+impl Bb {
+    /// Interleaves the bytes of `a` and `b` into a word of twice the width, least significant first:
+    /// the bytes of `a` land in the even byte positions and the bytes of `b` in the odd ones.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::interleave_bytes(0x1122u16, 0xaabbu16), 0xaa11_bb22u32);
+    /// ```
+    pub fn interleave_bytes<T: InterleaveBytes>(a: T, b: T) -> T::Wide {
+        T::interleave(a, b)
+    }
+
+    /// Splits the even and odd bytes of `w` back into two words, inverting [Bb::interleave_bytes].
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::deinterleave_bytes::<u16>(0xaa11_bb22), (0x1122, 0xaabb));
+    /// ```
+    pub fn deinterleave_bytes<T: InterleaveBytes>(w: T::Wide) -> (T, T) {
+        T::deinterleave(w)
+    }
+
+    /// Interleaves the nibbles of `a` and `b`, most significant first: `a` high, `b` high, `a` low, `b` low.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::interleave_nibbles(0x12, 0xab), 0x1a2b);
+    /// ```
+    pub fn interleave_nibbles(a: u8, b: u8) -> u16 {
+        let (a, b) = (a as u16, b as u16);
+        (a & 0xf0) << 8 | (b & 0xf0) << 4 | (a & 0xf) << 4 | b & 0xf
+    }
+
+    /// Splits the nibbles of `w` back into two bytes, inverting [Bb::interleave_nibbles].
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::deinterleave_nibbles(0x1a2b), (0x12, 0xab));
+    /// ```
+    pub fn deinterleave_nibbles(w: u16) -> (u8, u8) {
+        let a = (w >> 8 & 0xf0) | (w >> 4 & 0xf);
+        let b = (w >> 4 & 0xf0) | (w & 0xf);
+        (a as u8, b as u8)
+    }
+
+    /// Reverses the order of bits within each byte of `buf` in place,
+    /// converting between LSB-first and MSB-first bitstreams.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut buf = [0b0000_0001, 0b1100_0000];
+    /// Bb::reverse_bit_order(&mut buf);
+    /// assert_eq!(buf, [0b1000_0000, 0b0000_0011]);
+    /// ```
+    pub fn reverse_bit_order(buf: &mut [u8]) {
+        for b in buf {
+            *b = b.reverse_bits();
+        }
+    }
+
+    /// Reverses the whole bitstream in `buf` in place: the last bit of the last byte becomes the first bit of the first.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut buf = [0b0000_0001, 0b1100_0000];
+    /// Bb::reverse_bit_stream(&mut buf);
+    /// assert_eq!(buf, [0b0000_0011, 0b1000_0000]);
+    /// ```
+    pub fn reverse_bit_stream(buf: &mut [u8]) {
+        buf.reverse();
+        Bb::reverse_bit_order(buf);
+    }
+}
+
+#[doc(hidden)]
+pub trait WriteBytes {
+    fn write_le(self, buf: &mut [u8]);
+
+    fn write_be(self, buf: &mut [u8]);
+
+    fn write_ne(self, buf: &mut [u8]);
+}
+
+#[doc(hidden)]
+macro_rules! impl_write_bytes {
+    ($($T:ty)*) => {
+        $(
+            impl WriteBytes for $T {
+                fn write_le(self, buf: &mut [u8]) {
+                    write(buf, &self.to_le_bytes())
+                }
+
+                fn write_be(self, buf: &mut [u8]) {
+                    write(buf, &self.to_be_bytes())
+                }
+
+                fn write_ne(self, buf: &mut [u8]) {
+                    write(buf, &self.to_ne_bytes())
+                }
+            }
+        )*
+    };
+}
+
+fn write(buf: &mut [u8], bytes: &[u8]) {
+    assert_eq!(buf.len(), bytes.len(), "buffer length doesn't match the size of the value");
+    buf.copy_from_slice(bytes);
+}
+
+impl_write_bytes!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Writes the little endian bytes of `x` into `buf`, which must be exactly as long as `x` is large.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut buf = [0; 4];
+    /// Bb::write_le_bytes(0x0102_0304u32, &mut buf);
+    /// assert_eq!(buf, [4, 3, 2, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` doesn't match the size of `T`.
+    pub fn write_le_bytes<T: WriteBytes>(x: T, buf: &mut [u8]) {
+        x.write_le(buf)
+    }
+
+    /// Writes the big endian bytes of `x` into `buf`, which must be exactly as long as `x` is large.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` doesn't match the size of `T`.
+    pub fn write_be_bytes<T: WriteBytes>(x: T, buf: &mut [u8]) {
+        x.write_be(buf)
+    }
+
+    /// Writes the native endian bytes of `x` into `buf`, which must be exactly as long as `x` is large.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` doesn't match the size of `T`.
+    pub fn write_ne_bytes<T: WriteBytes>(x: T, buf: &mut [u8]) {
+        x.write_ne(buf)
+    }
+}
+
+#[doc(hidden)]
+pub trait ReadBytes: Sized {
+    fn read_le(buf: &[u8]) -> Self;
+
+    fn read_be(buf: &[u8]) -> Self;
+
+    fn read_ne(buf: &[u8]) -> Self;
+}
+
+fn read<const N: usize>(buf: &[u8]) -> [u8; N] {
+    match buf.first_chunk() {
+        Some(bytes) => *bytes,
+        None => panic!("buffer is shorter than the size of the value"),
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_read_bytes {
+    ($($T:ty)*) => {
+        $(
+            impl ReadBytes for $T {
+                fn read_le(buf: &[u8]) -> $T {
+                    <$T>::from_le_bytes(read(buf))
+                }
+
+                fn read_be(buf: &[u8]) -> $T {
+                    <$T>::from_be_bytes(read(buf))
+                }
+
+                fn read_ne(buf: &[u8]) -> $T {
+                    <$T>::from_ne_bytes(read(buf))
+                }
+            }
+        )*
+    };
+}
+
+impl_read_bytes!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Reads a little endian `T` from the start of `buf`. Any bytes past the size of `T` are ignored.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::read_le::<u32>(&[4, 3, 2, 1, 0xff]), 0x0102_0304);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than the size of `T`.
+    pub fn read_le<T: ReadBytes>(buf: &[u8]) -> T {
+        T::read_le(buf)
+    }
+
+    /// Reads a big endian `T` from the start of `buf`. Any bytes past the size of `T` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than the size of `T`.
+    pub fn read_be<T: ReadBytes>(buf: &[u8]) -> T {
+        T::read_be(buf)
+    }
+
+    /// Reads a native endian `T` from the start of `buf`. Any bytes past the size of `T` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than the size of `T`.
+    pub fn read_ne<T: ReadBytes>(buf: &[u8]) -> T {
+        T::read_ne(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assume_init_cast() {
+        let mut bytes = MaybeUninit::<[u8; 4]>::uninit();
+        let ptr = bytes.as_mut_ptr().cast::<u8>();
+        for (i, b) in 0x12345678u32.to_le_bytes().into_iter().enumerate() {
+            unsafe { ptr.add(i).write(b) };
+        }
+        let x = unsafe { Bb::assume_init_cast::<4, u32>(bytes) };
+        assert_eq!(u32::from_le(x), 0x12345678);
+    }
+
+    #[test]
+    fn rotate_bytes() {
+        let x = 0x1122_3344u32;
+        assert_eq!(Bb::rotate_bytes_left(x, 0), x);
+        assert_eq!(Bb::rotate_bytes_left(x, 1), 0x2233_4411);
+        assert_eq!(Bb::rotate_bytes_left(x, 3), 0x4411_2233);
+        assert_eq!(Bb::rotate_bytes_left(x, 4), x);
+        assert_eq!(Bb::rotate_bytes_left(x, 5), 0x2233_4411);
+        assert_eq!(Bb::rotate_bytes_right(x, 1), 0x4411_2233);
+        assert_eq!(Bb::rotate_bytes_right(x, 4), x);
+        assert_eq!(Bb::rotate_bytes_left(0x1122u16, 1), 0x2211);
+        let y = 0x0102_0304_0506_0708u64;
+        for n in 0..16 {
+            assert_eq!(Bb::rotate_bytes_right(Bb::rotate_bytes_left(y, n), n), y);
+        }
+        assert_eq!(Bb::rotate_bytes_left(y, 8), y);
+        assert_eq!(Bb::rotate_bytes_left(1u128, 15), 1 << 120);
+        assert_eq!(Bb::rotate_bytes_left(1u128, u32::MAX), 1 << 120);
+    }
+
+    #[test]
+    fn reverse_bit_order() {
+        let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+        let mut buf = bytes;
+        Bb::reverse_bit_order(&mut buf);
+        assert_eq!(buf, [0x80, 0xc4, 0xa2, 0xe6, 0x91, 0xd5, 0xb3, 0xf7]);
+        Bb::reverse_bit_order(&mut buf);
+        assert_eq!(buf, bytes);
+        Bb::reverse_bit_stream(&mut buf);
+        assert_eq!(u64::from_le_bytes(buf), u64::from_le_bytes(bytes).reverse_bits());
+        Bb::reverse_bit_stream(&mut buf);
+        assert_eq!(buf, bytes);
+        Bb::reverse_bit_order(&mut []);
+        Bb::reverse_bit_stream(&mut []);
+    }
+
+    #[test]
+    fn rotbytes_in() {
+        let x = 0x1122_3344_5566_7788u64;
+        assert_eq!(x * Bb.rotbytes_in::<4>(0), x);
+        assert_eq!(x * Bb.rotbytes_in::<4>(1), 0x2233_4411_6677_8855);
+        assert_eq!(x * Bb.rotbytes_in::<4>(3), 0x4411_2233_8855_6677);
+        assert_eq!(x * Bb.rotbytes_in::<4>(5), x * Bb.rotbytes_in::<4>(1));
+        let (hi, lo) = ((x >> 32) as u32, x as u32);
+        let lanes = (hi.rotate_left(8) as u64) << 32 | lo.rotate_left(8) as u64;
+        assert_eq!(x * Bb.rotbytes_in::<4>(1), lanes);
+        assert_eq!(x * Bb.rotbytes_in::<8>(1), Bb::rotate_bytes_left(x, 1));
+        assert_eq!(x * Bb.rotbytes_in::<2>(1), x * Bb.swapbytes16);
+        assert_eq!(x * Bb.rotbytes_in::<1>(7), x);
+        assert_eq!(-2i16 * Bb.rotbytes_in::<2>(1), -257);
+        assert_eq!(1u128 * Bb.rotbytes_in::<16>(15), 1 << 120);
+    }
+
+    #[test]
+    #[should_panic = "lane size doesn't divide the width"]
+    fn rotbytes_in_lane() {
+        let _ = 0u64 * Bb.rotbytes_in::<3>(1);
+    }
+
+    #[test]
+    fn interleave_nibbles() {
+        assert_eq!(Bb::interleave_nibbles(0, 0), 0);
+        assert_eq!(Bb::interleave_nibbles(0xff, 0), 0xf0f0);
+        assert_eq!(Bb::interleave_nibbles(0, 0xff), 0x0f0f);
+        assert_eq!(Bb::interleave_nibbles(0xf0, 0x0f), 0xf00f);
+        assert_eq!(Bb::interleave_nibbles(0x12, 0x34), 0x1324);
+        for a in 0..=u8::MAX {
+            for b in [0u8, 0x0f, 0x5a, 0xf0, 0xff] {
+                assert_eq!(Bb::deinterleave_nibbles(Bb::interleave_nibbles(a, b)), (a, b));
+            }
+        }
+        for w in (0..=u16::MAX).step_by(37) {
+            let (a, b) = Bb::deinterleave_nibbles(w);
+            assert_eq!(Bb::interleave_nibbles(a, b), w);
+        }
+    }
+
+    #[test]
+    fn interleave_bytes() {
+        assert_eq!(Bb::interleave_bytes(0x12u8, 0x34u8), 0x3412);
+        assert_eq!(Bb::interleave_bytes(0x0102_0304u32, 0x0a0b_0c0du32), 0x0a01_0b02_0c03_0d04);
+        assert_eq!(Bb::interleave_bytes(0u32, u32::MAX), 0xff00_ff00_ff00_ff00);
+        assert_eq!(Bb::deinterleave_bytes::<u32>(0xff00_ff00_ff00_ff00), (0, u32::MAX));
+        for (a, b) in [(0u16, 0u16), (0x1234, 0xabcd), (0xffff, 0x0001), (0x8000, 0x7fff)] {
+            assert_eq!(Bb::deinterleave_bytes::<u16>(Bb::interleave_bytes(a, b)), (a, b));
+        }
+        let (a, b) = (0x0123_4567_89ab_cdefu64, 0xfedc_ba98_7654_3210u64);
+        assert_eq!(Bb::deinterleave_bytes::<u64>(Bb::interleave_bytes(a, b)), (a, b));
+        let w = 0x1122_3344_5566_7788u64;
+        let (a, b) = Bb::deinterleave_bytes::<u32>(w);
+        assert_eq!(Bb::interleave_bytes(a, b), w);
+    }
+
+    #[test]
+    fn write_bytes() {
+        let mut buf = [0; 4];
+        Bb::write_le_bytes(0x0102_0304u32, &mut buf);
+        assert_eq!(buf, [4, 3, 2, 1]);
+        Bb::write_be_bytes(0x0102_0304u32, &mut buf);
+        assert_eq!(buf, [1, 2, 3, 4]);
+        Bb::write_ne_bytes(0x0102_0304u32, &mut buf);
+        assert_eq!(buf, 0x0102_0304u32.to_ne_bytes());
+        let mut packet = [0xaa; 7];
+        Bb::write_be_bytes(-2i16, &mut packet[1..3]);
+        Bb::write_le_bytes(0x0506_0708i32, &mut packet[3..]);
+        assert_eq!(packet, [0xaa, 0xff, 0xfe, 8, 7, 6, 5]);
+        Bb::write_le_bytes(7u8, &mut packet[..1]);
+        assert_eq!(packet[0], 7);
+    }
+
+    #[test]
+    #[should_panic = "buffer length doesn't match"]
+    fn write_bytes_mismatch() {
+        Bb::write_le_bytes(0u32, &mut [0; 5]);
+    }
+
+    #[test]
+    fn read_bytes() {
+        let buf: [u8; 16] = core::array::from_fn(|i| i as u8 + 1);
+        assert_eq!(Bb::read_le::<u8>(&buf), 1);
+        assert_eq!(Bb::read_be::<i8>(&buf), 1);
+        assert_eq!(Bb::read_le::<u16>(&buf), 0x0201);
+        assert_eq!(Bb::read_be::<u16>(&buf), 0x0102);
+        assert_eq!(Bb::read_le::<u32>(&buf), 0x0403_0201);
+        assert_eq!(Bb::read_be::<i32>(&buf), 0x0102_0304);
+        assert_eq!(Bb::read_le::<u64>(&buf), 0x0807_0605_0403_0201);
+        assert_eq!(Bb::read_be::<u64>(&buf[8..]), 0x090a_0b0c_0d0e_0f10);
+        assert_eq!(Bb::read_be::<u128>(&buf), u128::from_be_bytes(buf));
+        assert_eq!(Bb::read_ne::<i128>(&buf), i128::from_ne_bytes(buf));
+        assert_eq!(Bb::read_be::<i16>(&[0xff, 0xfe]), -2);
+        let mut out = [0; 8];
+        Bb::write_be_bytes(-3i64, &mut out);
+        assert_eq!(Bb::read_be::<i64>(&out), -3);
+    }
+
+    #[test]
+    #[should_panic = "buffer is shorter"]
+    fn read_bytes_short() {
+        let _ = Bb::read_le::<u32>(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn find_byte() {
+        let word = u64::from_le_bytes(*b"abcdefgh");
+        for (i, b) in b"abcdefgh".iter().enumerate() {
+            assert!(Bb::has_byte(word, *b));
+            assert_eq!(Bb::first_byte_index(word, *b), Some(i));
+        }
+        assert!(!Bb::has_byte(word, b'z'));
+        assert_eq!(Bb::first_byte_index(word, b'z'), None);
+        assert_eq!(Bb::first_byte_index(0u32, 0), Some(0));
+        assert_eq!(Bb::first_byte_index(0xffff_ffffu32, 0), None);
+        assert_eq!(Bb::first_byte_index(0x0100_0000u32, 1), Some(3));
+        assert_eq!(Bb::first_byte_index(0x0100_0000u32, 0), Some(0));
+        // a borrow out of a matching byte must not hide or misplace the match
+        assert_eq!(Bb::first_byte_index(0x0101_0100u32, 0), Some(0));
+        assert_eq!(Bb::first_byte_index(0x0100_0100u32, 1), Some(1));
+        assert_eq!(Bb::first_byte_index(0x8080_8000u32, 0x80), Some(1));
+        assert!(!Bb::has_byte(0x8080_8080u32, 0));
+        assert!(!Bb::has_byte(0x0101_0101u32, 0));
+    }
+}
@@ -0,0 +1,388 @@
+//! Nameable types of configured chains.
+//!
+//! Every chain starts at [Bb](struct@Bb). Accessing a field or calling a method on a chain `Prev`
+//! produces the chain of the corresponding type here, parameterized by `Prev`:
+//!
+//! - `Bb.u` is [`Unsigned`],
+//! - `Bb.u.t8` is [`Truncate8<Unsigned>`],
+//! - `Bb.u.t8.extend_to::<i32>()` is [`ExtendTo<i32, Truncate8<Unsigned>>`].
+//!
+//! Fields and methods without runtime arguments link the chain with an [`RL`], which is zero sized and `Copy`,
+//! so such a chain can be configured once, stored, and applied many times.
+//! A method with runtime arguments, like [`Shls`], ends the chain with an [`RA`] holding the arguments.
+//! No further fields may follow it, and it is `Copy` only if the arguments are,
+//! which closures given to `and_then` or `also` need not be.
+//!
+//! ```
+//! use yabe::Bb;
+//! use yabe::chain::{Truncate8, Unsigned};
+//!
+//! struct Narrow {
+//!     cast: Truncate8<Unsigned>,
+//! }
+//!
+//! let narrow = Narrow { cast: Bb.u.t8 };
+//! assert_eq!([-1i32, 0x180].map(|x| x * narrow.cast), [0xff, 0x80]);
+//! ```
+//!
+//! The result of applying a chain to a value of type `Src` is named by [`Bitcast<Src, Chain>`](Bitcast).
+
+use crate::Bb;
+use crate::RA;
+use crate::RL;
+
+/// Output type of the chain `Chain` applied to a value of type `Src`.
+///
+/// ```
+/// use yabe::Bb;
+/// use yabe::chain::{Bitcast, Extend64};
+///
+/// fn widen(x: u8) -> Bitcast<u8, Extend64> {
+///     x * Bb.e64
+/// }
+///
+/// assert_eq!(widen(0xff), 0xffu64);
+/// ```
+pub type Bitcast<Src, Chain> = <Src as core::ops::Mul<Chain>>::Output;
+
+/// `Prev.s`
+pub type Signed<Prev = Bb> = RL<crate::CastSigned, Prev>;
+
+/// `Prev.u`
+pub type Unsigned<Prev = Bb> = RL<crate::CastUnsigned, Prev>;
+
+/// `Prev.t8`
+pub type Truncate8<Prev = Bb> = RL<crate::Truncate8, Prev>;
+
+/// `Prev.t16`
+pub type Truncate16<Prev = Bb> = RL<crate::Truncate16, Prev>;
+
+/// `Prev.t32`
+pub type Truncate32<Prev = Bb> = RL<crate::Truncate32, Prev>;
+
+/// `Prev.t64`
+pub type Truncate64<Prev = Bb> = RL<crate::Truncate64, Prev>;
+
+/// `Prev.e16`
+pub type Extend16<Prev = Bb> = RL<crate::Extend16, Prev>;
+
+/// `Prev.e32`
+pub type Extend32<Prev = Bb> = RL<crate::Extend32, Prev>;
+
+/// `Prev.e64`
+pub type Extend64<Prev = Bb> = RL<crate::Extend64, Prev>;
+
+/// `Prev.e128`
+pub type Extend128<Prev = Bb> = RL<crate::Extend128, Prev>;
+
+/// `Prev.pdp`
+pub type ToPdp<Prev = Bb> = RL<crate::ToPdp, Prev>;
+
+/// `Prev.unpdp`
+pub type FromPdp<Prev = Bb> = RL<crate::FromPdp, Prev>;
+
+/// `Prev.swap16`
+pub type Swap16Lanes<Prev = Bb> = RL<crate::Swap16Lanes, Prev>;
+
+/// `Prev.swap32`
+pub type Swap32Lanes<Prev = Bb> = RL<crate::Swap32Lanes, Prev>;
+
+/// `Prev.swapbytes16`
+pub type SwapBytes16<Prev = Bb> = RL<crate::SwapBytes16, Prev>;
+
+/// `Prev.swapnib`
+pub type SwapNibbles<Prev = Bb> = RL<crate::SwapNibbles, Prev>;
+
+/// `Prev.reflect8`
+pub type Reflect8<Prev = Bb> = RL<crate::Reflect8, Prev>;
+
+/// `Prev.blsr`
+pub type ClearLowest<Prev = Bb> = RL<crate::ClearLowest, Prev>;
+
+/// `Prev.blsmsk`
+pub type MaskToLowest<Prev = Bb> = RL<crate::MaskToLowest, Prev>;
+
+/// `Prev.rev`
+pub type ReverseBits<Prev = Bb> = RL<crate::ReverseBits, Prev>;
+
+/// `Prev.id`
+pub type Identity<Prev = Bb> = RL<crate::Identity, Prev>;
+
+/// `Prev.ct8`
+pub type CheckedTruncate8<Prev = Bb> = RL<crate::CheckedTruncate8, Prev>;
+
+/// `Prev.ct16`
+pub type CheckedTruncate16<Prev = Bb> = RL<crate::CheckedTruncate16, Prev>;
+
+/// `Prev.ct32`
+pub type CheckedTruncate32<Prev = Bb> = RL<crate::CheckedTruncate32, Prev>;
+
+/// `Prev.ct64`
+pub type CheckedTruncate64<Prev = Bb> = RL<crate::CheckedTruncate64, Prev>;
+
+/// `Prev.exact`
+pub type Exact<Prev = Bb> = RL<crate::Exact, Prev>;
+
+/// `Prev.ones8`
+pub type PopcountPerByte<Prev = Bb> = RL<crate::PopcountPerByte, Prev>;
+
+/// `Prev.ones_comp`
+pub type OnesComplement<Prev = Bb> = RL<crate::OnesComplement, Prev>;
+
+/// `Prev.to_twos`
+pub type OnesToTwos<Prev = Bb> = RL<crate::OnesToTwos, Prev>;
+
+/// `Prev.from_twos`
+pub type TwosToOnes<Prev = Bb> = RL<crate::TwosToOnes, Prev>;
+
+/// `Prev.to_sm`
+pub type ToSignMagnitude<Prev = Bb> = RL<crate::ToSignMagnitude, Prev>;
+
+/// `Prev.from_sm`
+pub type FromSignMagnitude<Prev = Bb> = RL<crate::FromSignMagnitude, Prev>;
+
+/// `Prev.ilog2`
+pub type Ilog2<Prev = Bb> = RL<crate::Ilog2, Prev>;
+
+/// `Prev.ilog10`
+pub type Ilog10<Prev = Bb> = RL<crate::Ilog10, Prev>;
+
+/// `Prev.checked_ilog2`
+pub type CheckedIlog2<Prev = Bb> = RL<crate::CheckedIlog2, Prev>;
+
+/// `Prev.isqrt`
+pub type Isqrt<Prev = Bb> = RL<crate::Isqrt, Prev>;
+
+/// `Prev.norm_msb`
+pub type NormMsb<Prev = Bb> = RL<crate::NormMsb, Prev>;
+
+/// `Prev.sneg`
+pub type SaturatingNeg<Prev = Bb> = RL<crate::SaturatingNeg, Prev>;
+
+/// `Prev.revnib`
+pub type ReverseNibbles<Prev = Bb> = RL<crate::ReverseNibbles, Prev>;
+
+/// `Prev.ones`
+pub type CountOnes<Prev = Bb> = RL<crate::CountOnes, Prev>;
+
+/// `Prev.widen2`
+///
+/// ```compile_fail
+/// # use yabe::Bb;
+/// let _ = 5u128 * Bb.widen2;
+/// ```
+pub type Widen2<Prev = Bb> = RL<crate::Widen2, Prev>;
+
+/// `Prev.narrow2`
+///
+/// ```compile_fail
+/// # use yabe::Bb;
+/// let _ = 5u8 * Bb.narrow2;
+/// ```
+pub type Narrow2<Prev = Bb> = RL<crate::Narrow2, Prev>;
+
+/// `Prev.sat_u`
+pub type SatU<Prev = Bb> = RL<crate::SatU, Prev>;
+
+/// `Prev.sat_s`
+pub type SatS<Prev = Bb> = RL<crate::SatS, Prev>;
+
+/// `Prev.lrun`
+pub type LeadingRun<Prev = Bb> = RL<crate::LeadingRun, Prev>;
+
+/// `Prev.trun`
+pub type TrailingRun<Prev = Bb> = RL<crate::TrailingRun, Prev>;
+
+/// `Prev.lowb`
+pub type LowByte<Prev = Bb> = RL<crate::LowByte, Prev>;
+
+/// `Prev.highb`
+pub type HighByte<Prev = Bb> = RL<crate::HighByte, Prev>;
+
+/// `Prev.narrow2_sat`
+///
+/// ```compile_fail
+/// # use yabe::Bb;
+/// let _ = 5i8 * Bb.narrow2_sat;
+/// ```
+pub type Narrow2Sat<Prev = Bb> = RL<crate::Narrow2Sat, Prev>;
+
+/// `Prev.isnan`
+///
+/// ```compile_fail,E0277
+/// # use yabe::Bb;
+/// // error: `isnan` requires an `f32` or `f64` input, not `u32`
+/// let _ = 5u32 * Bb.isnan;
+/// ```
+pub type IsNan<Prev = Bb> = RL<crate::IsNan, Prev>;
+
+/// `Prev.isfinite`
+///
+/// ```compile_fail,E0277
+/// # use yabe::Bb;
+/// // error: `isfinite` requires an `f32` or `f64` input, not `i64`
+/// let _ = 5i64 * Bb.isfinite;
+/// ```
+pub type IsFinite<Prev = Bb> = RL<crate::IsFinite, Prev>;
+
+/// `Prev.issubnormal`
+///
+/// ```compile_fail,E0277
+/// # use yabe::Bb;
+/// // error: `issubnormal` requires an `f32` or `f64` input, not `u8`
+/// let _ = 5u8 * Bb.issubnormal;
+/// ```
+pub type IsSubnormal<Prev = Bb> = RL<crate::IsSubnormal, Prev>;
+
+/// `Prev.nibparity`
+pub type NibbleParity<Prev = Bb> = RL<crate::NibbleParity, Prev>;
+
+/// `Prev.f16_rne`
+///
+/// ```compile_fail,E0277
+/// # use yabe::Bb;
+/// // error: `f16_rne` and `f16_rtz` require an `f32` input, not `f64`
+/// let _ = 5f64 * Bb.f16_rne;
+/// ```
+pub type F16Rne<Prev = Bb> = RL<crate::F16Rne, Prev>;
+
+/// `Prev.f16_rtz`
+///
+/// ```compile_fail,E0277
+/// # use yabe::Bb;
+/// // error: `f16_rne` and `f16_rtz` require an `f32` input, not `u32`
+/// let _ = 5u32 * Bb.f16_rtz;
+/// ```
+pub type F16Rtz<Prev = Bb> = RL<crate::F16Rtz, Prev>;
+
+/// `Prev.truncate_to::<T>()`
+pub type TruncateTo<T, Prev = Bb> = RL<crate::TruncateTo<T>, Prev>;
+
+/// `Prev.extend_to::<T>()`
+pub type ExtendTo<T, Prev = Bb> = RL<crate::ExtendTo<T>, Prev>;
+
+/// `Prev.sat::<T>()`
+pub type Saturate<T, Prev = Bb> = RL<crate::convert::Saturate<T>, Prev>;
+
+/// `Prev.trunc::<N>()`
+pub type Trunc<const N: u32, Prev = Bb> = RL<crate::fields::Trunc<N>, Prev>;
+
+/// `Prev.wrapping_to::<T>()`
+pub type WrappingTo<T, Prev = Bb> = RL<crate::convert::WrappingTo<T>, Prev>;
+
+/// `Prev.try_to::<T>()`
+pub type TryTo<T, Prev = Bb> = RL<crate::convert::TryTo<T>, Prev>;
+
+/// `Prev.endian::<E>()`
+pub type ToEndian<E, Prev = Bb> = RL<crate::endian::ToEndian<E>, Prev>;
+
+/// `Prev.swap_if::<E>()`
+pub type SwapIf<E, Prev = Bb> = RL<crate::endian::SwapIf<E>, Prev>;
+
+/// `Prev.rev_if::<E>()`
+pub type RevIf<E, Prev = Bb> = RL<crate::endian::RevIf<E>, Prev>;
+
+/// `Prev.shls(n)`
+pub type Shls<Prev = Bb> = RA<crate::arith::SaturatingShl, Prev>;
+
+/// `Prev.clampbits(n)`
+pub type ClampBits<Prev = Bb> = RA<crate::arith::ClampBits, Prev>;
+
+/// `Prev.shift(n)`
+pub type Shift<Prev = Bb> = RA<crate::arith::SignedShift, Prev>;
+
+/// `Prev.rotbytes_in::<LANE>(n)`
+pub type RotateBytesIn<const LANE: usize, Prev = Bb> = RA<crate::bytes::RotateBytesIn<LANE>, Prev>;
+
+/// `Prev.rtrunc(n)`
+pub type ReverseTruncate<Prev = Bb> = RA<crate::bits::ReverseTruncate, Prev>;
+
+/// `Prev.fence(lo, hi)`
+pub type Fence<Prev = Bb> = RA<crate::bits::Fence, Prev>;
+
+/// `Prev.deposit(lo, len, value)`
+pub type Deposit<Prev = Bb> = RA<crate::bits::Deposit, Prev>;
+
+/// `Prev.cshl(n)`
+pub type Cshl<Prev = Bb> = RA<crate::arith::CheckedShl, Prev>;
+
+/// `Prev.cshr(n)`
+pub type Cshr<Prev = Bb> = RA<crate::arith::CheckedShr, Prev>;
+
+/// `Prev.divceil(n)`
+pub type DivCeil<N, Prev = Bb> = RA<crate::arith::DivCeil<N>, Prev>;
+
+/// `Prev.nextmul(n)`
+pub type NextMultiple<N, Prev = Bb> = RA<crate::arith::NextMultiple<N>, Prev>;
+
+/// `Prev.checked_shl_for::<T>(n)`
+pub type CheckedShlFor<T, Prev = Bb> = RA<crate::arith::CheckedShlFor<T>, Prev>;
+
+/// `Prev.aligned(n)`
+pub type Aligned<Prev = Bb> = RA<crate::arith::IsAligned, Prev>;
+
+/// `Prev.asr_round(n)`
+pub type AsrRound<Prev = Bb> = RA<crate::arith::RoundingShr, Prev>;
+
+/// `Prev.and_then(f)`
+pub type AndThen<F, Prev = Bb> = RA<crate::fallible::AndThen<F>, Prev>;
+
+/// `Prev.also(f)`
+pub type Also<F, Prev = Bb> = RA<crate::inspect::Also<F>, Prev>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Big;
+
+    struct Pipeline {
+        narrow: Truncate8<Unsigned>,
+        widen: ExtendTo<i64, Signed<Truncate16>>,
+        swap: ToEndian<Big, Extend32>,
+        shift: Shls<Extend32>,
+    }
+
+    #[test]
+    fn stored_chains() {
+        let p = Pipeline {
+            narrow: Bb.u.t8,
+            widen: Bb.t16.s.extend_to::<i64>(),
+            swap: Bb.e32.endian::<Big>(),
+            shift: Bb.e32.shls(4),
+        };
+        let src = [0x1234_ff80u32, 0x8000, 0xffff_ffff];
+        assert_eq!(src.map(|x| x * p.narrow), [0x80, 0x00, 0xff]);
+        assert_eq!(src.map(|x| x * p.widen), [-0x80, -0x8000, -1]);
+        let bytes = [0x12u16, 0xfff];
+        assert_eq!(bytes.map(|x| x * p.swap), [0x12u32.to_be(), 0xfffu32.to_be()]);
+        assert_eq!(bytes.map(|x| x * p.shift), [0x120, 0xfff0]);
+        let mut out = [0u8; 3];
+        for (o, x) in out.iter_mut().zip(src) {
+            *o = x * p.narrow;
+        }
+        assert_eq!(out, [0x80, 0x00, 0xff]);
+    }
+
+    #[test]
+    fn bitcast() {
+        fn narrow(x: i32) -> Bitcast<i32, Truncate8<Unsigned>> {
+            x * Bb.u.t8
+        }
+
+        fn checked(x: u32) -> Bitcast<u32, CheckedTruncate16> {
+            x * Bb.ct16
+        }
+
+        fn apply<T: core::ops::Mul<C>, C>(x: T, chain: C) -> Bitcast<T, C> {
+            x * chain
+        }
+
+        assert_eq!(narrow(-1), 0xffu8);
+        assert_eq!(checked(0x1_0000), None::<u16>);
+        assert_eq!(checked(5), Some(5));
+        let x: Bitcast<u16, ExtendTo<i64, Signed>> = apply(0xffffu16, Bb.s.extend_to::<i64>());
+        assert_eq!(x, -1i64);
+        let x: Bitcast<u8, Shls<Extend32>> = 0x80u8 * Bb.e32.shls(4);
+        assert_eq!(x, 0x800u32);
+    }
+}
@@ -0,0 +1,465 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::AppliedTo;
+use crate::Bb;
+use crate::CastFrom;
+use crate::CastSigned;
+use crate::Narrow2;
+use crate::Narrow2Sat;
+use crate::SatS;
+use crate::SatU;
+use crate::Widen2;
+use crate::RL;
+
+impl Bb {
+    /// Converts any integer to the canonical type `T` with [CastFrom], for generic code.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// fn key<T>(x: T) -> u32 where u32: yabe::CastFrom<T> {
+    ///     Bb::normalize::<u32, _>(x)
+    /// }
+    ///
+    /// assert_eq!(key(5u8), 5);
+    /// assert_eq!(key(-1i64), u32::MAX);
+    /// ```
+    pub fn normalize<T: CastFrom<Src>, Src>(x: Src) -> T {
+        T::cast_from(x)
+    }
+}
+
+/// Integer conversion clamping the value into the range of the target type.
+pub trait SaturatingFrom<Src> {
+    fn saturating_from(src: Src) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! saturating_from {
+    ($($S:ty)* => $T:tt) => {
+        $(
+            saturating_from!(@ $S => $T);
+        )*
+    };
+    (@ $S:ty => [$($T:ty)*]) => {
+        $(
+            impl SaturatingFrom<$S> for $T {
+                #[allow(unused_comparisons)]
+                fn saturating_from(src: $S) -> $T {
+                    match <$T>::try_from(src) {
+                        Ok(x) => x,
+                        Err(_) if src < 0 => <$T>::MIN,
+                        Err(_) => <$T>::MAX,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+saturating_from!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 => [i8 u8 i16 u16 i32 u32 i64 u64 i128 u128]);
+saturating_from!(isize usize => [isize usize]);
+
+#[doc(hidden)]
+pub struct Saturate<T>(PhantomData<T>);
+
+impl<U> CastSigned<U> {
+    /// Converts to `T`, clamping the value into the range of `T`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(1000i32 * Bb.sat::<u8>(), 255u8);
+    /// assert_eq!(-5i32 * Bb.sat::<u8>(), 0u8);
+    /// assert_eq!(u64::MAX * Bb.sat::<i16>(), i16::MAX);
+    /// ```
+    pub fn sat<T>(&self) -> RL<Saturate<T>, U> {
+        RL(PhantomData)
+    }
+}
+
+impl<S, T: SaturatingFrom<S>> AppliedTo<S> for Saturate<T> {
+    type Output = T;
+
+    fn apply(src: S) -> T {
+        T::saturating_from(src)
+    }
+}
+
+impl Bb {
+    /// Converts every element of `src` into `dst` like [sat](CastSigned::sat),
+    /// producing the number of elements that were clamped.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut dst = [0i8; 4];
+    /// assert_eq!(Bb::sat_cast_slice_counted(&[-300i16, -5, 100, 200], &mut dst), 2);
+    /// assert_eq!(dst, [-128, -5, 100, 127]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` differ in length.
+    pub fn sat_cast_slice_counted<S: Copy, D: SaturatingFrom<S> + TryFrom<S>>(src: &[S], dst: &mut [D]) -> u32 {
+        assert_eq!(src.len(), dst.len(), "destination length doesn't match the source");
+        let mut clipped = 0;
+        for (d, &x) in dst.iter_mut().zip(src) {
+            *d = D::try_from(x).unwrap_or_else(|_| {
+                clipped += 1;
+                D::saturating_from(x)
+            });
+        }
+        clipped
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_sat_signedness {
+    ($($S:ty => $U:ty),*) => {
+        $(
+            impl AppliedTo<$S> for SatU {
+                type Output = $U;
+
+                fn apply(src: $S) -> $U {
+                    <$U>::saturating_from(src)
+                }
+            }
+
+            impl AppliedTo<$U> for SatU {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    src
+                }
+            }
+
+            impl AppliedTo<$U> for SatS {
+                type Output = $S;
+
+                fn apply(src: $U) -> $S {
+                    <$S>::saturating_from(src)
+                }
+            }
+
+            impl AppliedTo<$S> for SatS {
+                type Output = $S;
+
+                fn apply(src: $S) -> $S {
+                    src
+                }
+            }
+        )*
+    };
+}
+
+impl_sat_signedness!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+#[doc(hidden)]
+pub struct WrappingTo<T>(PhantomData<T>);
+
+impl<U> CastSigned<U> {
+    /// Converts to `T` like an `as` cast: truncation keeps the low bits, and extension follows source signedness.
+    ///
+    /// Spells out the wrapping intent of the `t{width}` fields, producing the same code.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(0x1ffu16 * Bb.wrapping_to::<u8>(), 0xffu8);
+    /// assert_eq!(0x1ffu16 * Bb.wrapping_to::<i8>(), -1i8);
+    /// assert_eq!(-1i8 * Bb.wrapping_to::<u32>(), u32::MAX);
+    /// ```
+    pub fn wrapping_to<T>(&self) -> RL<WrappingTo<T>, U> {
+        RL(PhantomData)
+    }
+}
+
+impl<S, T: CastFrom<S>> AppliedTo<S> for WrappingTo<T> {
+    type Output = T;
+
+    fn apply(src: S) -> T {
+        T::cast_from(src)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_widen2 {
+    ($($T:ty => $W:ty),*) => {
+        $(
+            impl AppliedTo<$T> for Widen2 {
+                type Output = $W;
+
+                fn apply(src: $T) -> $W {
+                    src as $W
+                }
+            }
+
+            impl AppliedTo<$W> for Narrow2 {
+                type Output = $T;
+
+                fn apply(src: $W) -> $T {
+                    src as $T
+                }
+            }
+
+            impl AppliedTo<$W> for Narrow2Sat {
+                type Output = $T;
+
+                fn apply(src: $W) -> $T {
+                    <$T>::saturating_from(src)
+                }
+            }
+        )*
+    };
+}
+
+impl_widen2!(i8 => i16, u8 => u16, i16 => i32, u16 => u32, i32 => i64, u32 => u64, i64 => i128, u64 => u128);
+
+/// Error of a conversion to a type which can't represent the value.
+///
+/// Implements [`core::error::Error`] with the `error` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BitcastError {
+    /// The value is greater than the maximum of the target type.
+    Overflow {
+        /// Name of the target type.
+        target: &'static str,
+    },
+    /// The value is less than the minimum of the target type.
+    Underflow {
+        /// Name of the target type.
+        target: &'static str,
+    },
+    /// The value is a floating point NaN.
+    Nan,
+}
+
+impl fmt::Display for BitcastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitcastError::Overflow { target } => write!(f, "overflow casting to {target}"),
+            BitcastError::Underflow { target } => write!(f, "underflow casting to {target}"),
+            BitcastError::Nan => f.write_str("value is not a number"),
+        }
+    }
+}
+
+#[cfg(feature = "error")]
+impl core::error::Error for BitcastError {}
+
+#[doc(hidden)]
+pub struct TryTo<T>(PhantomData<T>);
+
+impl<U> CastSigned<U> {
+    /// Converts to `T`, failing if the value isn't exactly representable in `T`.
+    ///
+    /// Named `try_to` since `try` is a reserved keyword.
+    ///
+    /// ```
+    /// # use yabe::{Bb, BitcastError};
+    /// assert_eq!(200u16 * Bb.try_to::<u8>(), Ok(200u8));
+    /// assert_eq!(300u16 * Bb.try_to::<u8>(), Err(BitcastError::Overflow { target: "u8" }));
+    /// assert_eq!(-1i16 * Bb.try_to::<u8>(), Err(BitcastError::Underflow { target: "u8" }));
+    /// ```
+    pub fn try_to<T>(&self) -> RL<TryTo<T>, U> {
+        RL(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! impl_try_to {
+    ($($T:ty)*) => {
+        $(
+            impl<S: Copy + Default + PartialOrd> AppliedTo<S> for TryTo<$T> where $T: TryFrom<S> {
+                type Output = Result<$T, BitcastError>;
+
+                fn apply(src: S) -> Result<$T, BitcastError> {
+                    <$T>::try_from(src).map_err(|_| match src < S::default() {
+                        true => BitcastError::Underflow { target: stringify!($T) },
+                        false => BitcastError::Overflow { target: stringify!($T) },
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_to!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bb;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(Bb::normalize::<u32, _>(5u8), 5);
+        assert_eq!(Bb::normalize::<u32, _>(-1i8), u32::MAX);
+        assert_eq!(Bb::normalize::<u32, _>(0x1_2345_6789u64), 0x2345_6789);
+        assert_eq!(Bb::normalize::<u32, _>(u128::MAX), u32::MAX);
+        assert_eq!(Bb::normalize::<u32, _>(7u32), 7);
+        assert_eq!(Bb::normalize::<i64, _>(u32::MAX), u32::MAX as i64);
+        assert_eq!(Bb::normalize::<i64, _>(-2i16), -2);
+    }
+
+    #[test]
+    fn sat_signedness() {
+        assert_eq!(-1i32 * Bb.sat_u, 0u32);
+        assert_eq!(i8::MIN * Bb.sat_u, 0u8);
+        assert_eq!(i8::MAX * Bb.sat_u, 127u8);
+        assert_eq!(200u8 * Bb.sat_u, 200u8);
+        assert_eq!(u8::MAX * Bb.sat_s, i8::MAX);
+        assert_eq!(128u8 * Bb.sat_s, i8::MAX);
+        assert_eq!(127u8 * Bb.sat_s, 127i8);
+        assert_eq!(-5i8 * Bb.sat_s, -5i8);
+        assert_eq!(isize::MIN * Bb.sat_u, 0usize);
+        assert_eq!(usize::MAX * Bb.sat_s, isize::MAX);
+        for x in [i64::MIN, -1, 0, 1, i64::MAX] {
+            assert_eq!(x * Bb.sat_u, x * Bb.sat::<u64>());
+            assert_eq!(x * Bb.u.sat_s, x * Bb.u.sat::<i64>());
+        }
+        assert_eq!(-1i16 * Bb.sat_u.sat_s, 0i16);
+        assert_eq!(-1i16 * Bb.u.sat_s.sat_u, i16::MAX as u16);
+    }
+
+    #[test]
+    fn sat_cast_slice_counted() {
+        let src = [0i32, 40_000, -40_000, 32_767, -32_768, 32_768, 1];
+        let mut dst = [0i16; 7];
+        assert_eq!(Bb::sat_cast_slice_counted(&src, &mut dst), 3);
+        assert_eq!(dst, [0, i16::MAX, i16::MIN, 32_767, -32_768, i16::MAX, 1]);
+        let mut dst = [0u8; 3];
+        assert_eq!(Bb::sat_cast_slice_counted(&[1u8, 2, 3], &mut dst), 0);
+        assert_eq!(dst, [1, 2, 3]);
+        assert_eq!(Bb::sat_cast_slice_counted(&[-1i8, 0, -128], &mut dst), 2);
+        assert_eq!(dst, [0, 0, 0]);
+        assert_eq!(Bb::sat_cast_slice_counted::<u8, u64>(&[], &mut []), 0);
+        let mut wide = [0i64; 2];
+        assert_eq!(Bb::sat_cast_slice_counted(&[u64::MAX, 5], &mut wide), 1);
+        assert_eq!(wide, [i64::MAX, 5]);
+    }
+
+    #[test]
+    #[should_panic = "destination length doesn't match the source"]
+    fn sat_cast_slice_counted_mismatch() {
+        let _ = Bb::sat_cast_slice_counted(&[1u16, 2], &mut [0u8; 3]);
+    }
+
+    #[test]
+    fn sat() {
+        assert_eq!(1000i32 * Bb.sat::<u8>(), 255);
+        assert_eq!(255i32 * Bb.sat::<u8>(), 255);
+        assert_eq!(-5i32 * Bb.sat::<u8>(), 0);
+        assert_eq!(-129i32 * Bb.sat::<i8>(), i8::MIN);
+        assert_eq!(128i32 * Bb.sat::<i8>(), i8::MAX);
+        assert_eq!(-128i32 * Bb.sat::<i8>(), -128);
+        // cross signedness, same width
+        assert_eq!(-1i32 * Bb.sat::<u32>(), 0);
+        assert_eq!(u32::MAX * Bb.sat::<i32>(), i32::MAX);
+        assert_eq!(i32::MAX * Bb.sat::<u32>(), i32::MAX as u32);
+        // widening never clamps
+        assert_eq!(-1i8 * Bb.sat::<i128>(), -1);
+        assert_eq!(u64::MAX * Bb.sat::<u128>(), u64::MAX as u128);
+        assert_eq!(i8::MIN * Bb.sat::<u64>(), 0);
+        assert_eq!(u128::MAX * Bb.sat::<i128>(), i128::MAX);
+        assert_eq!(i128::MIN * Bb.sat::<u8>(), 0);
+        assert_eq!(0x1_0000u32 * Bb.sat::<u16>().t8, 0xff);
+    }
+
+    #[test]
+    fn bitcast_error_display() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!(BitcastError::Overflow { target: "u8" }.to_string(), "overflow casting to u8");
+        assert_eq!(BitcastError::Underflow { target: "i16" }.to_string(), "underflow casting to i16");
+        assert_eq!((300u16 * Bb.try_to::<u8>()).unwrap_err().to_string(), "overflow casting to u8");
+        assert_eq!((-1i64 * Bb.try_to::<usize>()).unwrap_err().to_string(), "underflow casting to usize");
+        assert_eq!(BitcastError::Nan.to_string(), "value is not a number");
+    }
+
+    #[cfg(feature = "error")]
+    #[test]
+    fn bitcast_error_source() {
+        let e: &dyn core::error::Error = &BitcastError::Overflow { target: "u8" };
+        assert!(e.source().is_none());
+    }
+
+    #[test]
+    fn wrapping_to() {
+        for x in [0u64, 1, 0x7f, 0x80, 0xff, 0x1234_5678, 0x8000_0000_0000_0000, u64::MAX] {
+            assert_eq!(x * Bb.wrapping_to::<u8>(), x * Bb.t8);
+            assert_eq!(x * Bb.wrapping_to::<u16>(), x * Bb.t16);
+            assert_eq!(x * Bb.wrapping_to::<u32>(), x * Bb.t32);
+            assert_eq!(x * Bb.wrapping_to::<i8>(), x * Bb.t8.s);
+            assert_eq!(x * Bb.s.wrapping_to::<i32>(), x * Bb.s.t32);
+            assert_eq!(x * Bb.s.wrapping_to::<u128>(), x * Bb.s.e128.u);
+        }
+        assert_eq!(0x1_0000u32 * Bb.wrapping_to::<u16>().e64, 0);
+    }
+
+    #[test]
+    fn widen2_narrow2() {
+        assert_eq!(0xffu8 * Bb.widen2, 0xffu16);
+        assert_eq!(-1i8 * Bb.widen2, -1i16);
+        assert_eq!(u16::MAX * Bb.widen2, u16::MAX as u32);
+        assert_eq!(i16::MIN * Bb.widen2, i16::MIN as i32);
+        assert_eq!(u32::MAX * Bb.widen2, u32::MAX as u64);
+        assert_eq!(-2i32 * Bb.widen2, -2i64);
+        assert_eq!(u64::MAX * Bb.widen2, u64::MAX as u128);
+        assert_eq!(i64::MIN * Bb.widen2, i64::MIN as i128);
+        assert_eq!(0x1234u16 * Bb.narrow2, 0x34u8);
+        assert_eq!(-0x80i16 * Bb.narrow2, -0x80i8);
+        assert_eq!(0x1234_5678u32 * Bb.narrow2, 0x5678u16);
+        assert_eq!(0x1234_5678_9abc_def0u64 * Bb.narrow2, 0x9abc_def0u32);
+        assert_eq!(-1i128 * Bb.narrow2, -1i64);
+        assert_eq!(u128::MAX * Bb.narrow2, u64::MAX);
+        assert_eq!(0xffu8 * Bb.widen2.widen2.widen2.widen2, 0xffu128);
+        for x in [0u32, 1, 0x8000_0000, u32::MAX] {
+            assert_eq!(x * Bb.widen2.narrow2, x);
+        }
+    }
+
+    #[test]
+    fn narrow2_sat() {
+        assert_eq!(0x34u16 * Bb.narrow2_sat, 0x34u8);
+        assert_eq!(0x1234u16 * Bb.narrow2_sat, u8::MAX);
+        assert_eq!(-0x80i16 * Bb.narrow2_sat, i8::MIN);
+        assert_eq!(-0x81i16 * Bb.narrow2_sat, i8::MIN);
+        assert_eq!(0x80i16 * Bb.narrow2_sat, i8::MAX);
+        assert_eq!(-5i32 * Bb.narrow2_sat, -5i16);
+        assert_eq!(0x1234_5678u32 * Bb.narrow2_sat, u16::MAX);
+        assert_eq!(u64::MAX * Bb.narrow2_sat, u32::MAX);
+        assert_eq!(i128::MIN * Bb.narrow2_sat, i64::MIN);
+        assert_eq!(u64::MAX as u128 * Bb.narrow2_sat, u64::MAX);
+        assert_eq!(u32::MAX * Bb.narrow2_sat.narrow2_sat, u8::MAX);
+        for x in [0u16, 1, 0x7f, 0x80, 0xff, 0x100, u16::MAX] {
+            assert_eq!(x * Bb.narrow2_sat, x * Bb.sat::<u8>());
+        }
+    }
+
+    #[test]
+    fn try_to() {
+        use BitcastError::*;
+
+        // widening
+        assert_eq!(-1i8 * Bb.try_to::<i64>(), Ok(-1));
+        assert_eq!(u32::MAX * Bb.try_to::<u64>(), Ok(u32::MAX as u64));
+        assert_eq!(u64::MAX * Bb.try_to::<i128>(), Ok(u64::MAX as i128));
+        assert_eq!(-1i8 * Bb.try_to::<u64>(), Err(Underflow { target: "u64" }));
+        // narrowing in range
+        assert_eq!(255u16 * Bb.try_to::<u8>(), Ok(255));
+        assert_eq!(-128i64 * Bb.try_to::<i8>(), Ok(-128));
+        assert_eq!(127u128 * Bb.try_to::<i8>(), Ok(127));
+        // narrowing out of range
+        assert_eq!(300u16 * Bb.try_to::<u8>(), Err(Overflow { target: "u8" }));
+        assert_eq!(128i64 * Bb.try_to::<i8>(), Err(Overflow { target: "i8" }));
+        assert_eq!(-129i64 * Bb.try_to::<i8>(), Err(Underflow { target: "i8" }));
+        assert_eq!(i128::MIN * Bb.try_to::<u8>(), Err(Underflow { target: "u8" }));
+        // same width
+        assert_eq!(u32::MAX * Bb.try_to::<i32>(), Err(Overflow { target: "i32" }));
+        assert_eq!(7u32 * Bb.try_to::<u32>(), Ok(7));
+        assert_eq!(
+            300u16 * Bb.try_to::<u8>().and_then(|x: u8| Ok::<_, BitcastError>(x.reverse_bits())),
+            Err(Overflow { target: "u8" }),
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use crate::Bb;
+
+#[doc(hidden)]
+pub trait Bcd: Sized {
+    fn bcd_decode(self) -> Option<Self>;
+
+    fn bcd_encode(self) -> Option<Self>;
+}
+
+#[doc(hidden)]
+macro_rules! impl_bcd {
+    ($($T:ty)*) => {
+        $(
+            impl Bcd for $T {
+                fn bcd_decode(self) -> Option<$T> {
+                    let mut res: $T = 0;
+                    for i in (0..<$T>::BITS / 4).rev() {
+                        match self >> (4 * i) & 0xf {
+                            d @ 0..=9 => res = res * 10 + d,
+                            _ => return None,
+                        }
+                    }
+                    Some(res)
+                }
+
+                fn bcd_encode(self) -> Option<$T> {
+                    let mut x = self;
+                    let mut res: $T = 0;
+                    for i in 0..<$T>::BITS / 4 {
+                        res |= x % 10 << (4 * i);
+                        x /= 10;
+                    }
+                    match x {
+                        0 => Some(res),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bcd!(u8 u16 u32);
+
+impl Bb {
+    /// Decodes packed BCD, 4 bits per decimal digit, producing `None` if any nibble is above 9.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bcd_decode(0x23u8), Some(23));
+    /// assert_eq!(Bb::bcd_decode(0x2au8), None);
+    /// ```
+    pub fn bcd_decode<T: Bcd>(x: T) -> Option<T> {
+        x.bcd_decode()
+    }
+
+    /// Encodes `x` as packed BCD, producing `None` if it has too many digits to fit.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::bcd_encode(23u8), Some(0x23));
+    /// assert_eq!(Bb::bcd_encode(100u8), None);
+    /// ```
+    pub fn bcd_encode<T: Bcd>(x: T) -> Option<T> {
+        x.bcd_encode()
+    }
+}
+
+impl Bb {
+    /// Value of an ASCII hex digit, either case, or `None` if `b` isn't one.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::hex_to_nibble(b'A'), Some(10));
+    /// assert_eq!(Bb::hex_to_nibble(b'g'), None);
+    /// ```
+    pub const fn hex_to_nibble(b: u8) -> Option<u8> {
+        match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            b'a'..=b'f' => Some(b - b'a' + 10),
+            b'A'..=b'F' => Some(b - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    /// Uppercase ASCII hex digit of the low nibble of `n`. The high nibble is ignored.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::nibble_to_hex(10), b'A');
+    /// ```
+    pub const fn nibble_to_hex(n: u8) -> u8 {
+        b"0123456789ABCDEF"[(n & 0xf) as usize]
+    }
+
+    /// Lowercase ASCII hex digit of the low nibble of `n`. The high nibble is ignored.
+    pub const fn nibble_to_hex_lower(n: u8) -> u8 {
+        b"0123456789abcdef"[(n & 0xf) as usize]
+    }
+
+    /// Value of an ASCII decimal digit, or `None` if `b` isn't one.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::dec_to_digit(b'7'), Some(7));
+    /// assert_eq!(Bb::dec_to_digit(b'a'), None);
+    /// ```
+    pub const fn dec_to_digit(b: u8) -> Option<u8> {
+        match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            _ => None,
+        }
+    }
+
+    /// ASCII decimal digit of `d`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::digit_to_dec(7), b'7');
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `d` is greater than 9.
+    pub const fn digit_to_dec(d: u8) -> u8 {
+        assert!(d <= 9, "digit exceeds 9");
+        b'0' + d
+    }
+}
+
+#[doc(hidden)]
+pub trait Hex: Sized {
+    fn write_hex(self, buf: &mut [u8], digits: &[u8; 16]) -> usize;
+
+    fn parse_hex(ascii: &[u8]) -> Option<Self>;
+}
+
+#[doc(hidden)]
+macro_rules! impl_hex {
+    ($($S:ty, $U:ty);*) => {
+        $(
+            impl Hex for $U {
+                fn write_hex(self, buf: &mut [u8], digits: &[u8; 16]) -> usize {
+                    let len = <$U>::BITS as usize / 4;
+                    assert!(buf.len() >= len, "buffer is shorter than the hex digits");
+                    for (i, b) in buf[..len].iter_mut().rev().enumerate() {
+                        *b = digits[(self >> (4 * i) & 0xf) as usize];
+                    }
+                    len
+                }
+
+                fn parse_hex(ascii: &[u8]) -> Option<$U> {
+                    if ascii.len() != <$U>::BITS as usize / 4 {
+                        return None;
+                    }
+                    let mut res: $U = 0;
+                    for &b in ascii {
+                        res = res << 4 | Bb::hex_to_nibble(b)? as $U;
+                    }
+                    Some(res)
+                }
+            }
+
+            impl Hex for $S {
+                fn write_hex(self, buf: &mut [u8], digits: &[u8; 16]) -> usize {
+                    (self as $U).write_hex(buf, digits)
+                }
+
+                fn parse_hex(ascii: &[u8]) -> Option<$S> {
+                    <$U>::parse_hex(ascii).map(|x| x as $S)
+                }
+            }
+        )*
+    };
+}
+
+impl_hex!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128);
+
+impl Bb {
+    /// Writes `x` as uppercase ASCII hex into the start of `buf`, two digits per byte of `T` with leading zeros,
+    /// returning the number of bytes written. Signed values are written as their two's complement bits.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut buf = [0; 8];
+    /// assert_eq!(Bb::hex(0xbeefu32, &mut buf), 8);
+    /// assert_eq!(&buf, b"0000BEEF");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than the hex digits of `T`.
+    pub fn hex<T: Hex>(x: T, buf: &mut [u8]) -> usize {
+        x.write_hex(buf, b"0123456789ABCDEF")
+    }
+
+    /// Writes `x` as lowercase ASCII hex into the start of `buf`, like [Bb::hex].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than the hex digits of `T`.
+    pub fn hex_lower<T: Hex>(x: T, buf: &mut [u8]) -> usize {
+        x.write_hex(buf, b"0123456789abcdef")
+    }
+
+    /// Parses fixed-width ASCII hex in either case, inverting [Bb::hex]. Produces `None` if `ascii`
+    /// contains a non-hex character or isn't exactly two digits per byte of `T` long.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::parse_hex::<u32>(b"0000beEF"), Some(0xbeef));
+    /// assert_eq!(Bb::parse_hex::<u32>(b"beef"), None);
+    /// ```
+    pub fn parse_hex<T: Hex>(ascii: &[u8]) -> Option<T> {
+        T::parse_hex(ascii)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    #[test]
+    fn bcd() {
+        assert_eq!(Bb::bcd_decode(0x00u8), Some(0));
+        assert_eq!(Bb::bcd_decode(0x99u8), Some(99));
+        assert_eq!(Bb::bcd_decode(0xa0u8), None);
+        assert_eq!(Bb::bcd_decode(0x0fu8), None);
+        assert_eq!(Bb::bcd_decode(0x1234u16), Some(1234));
+        assert_eq!(Bb::bcd_decode(0x9999u16), Some(9999));
+        assert_eq!(Bb::bcd_decode(0x12f4u16), None);
+        assert_eq!(Bb::bcd_decode(0x9999_9999u32), Some(99_999_999));
+        assert_eq!(Bb::bcd_encode(0u8), Some(0));
+        assert_eq!(Bb::bcd_encode(99u8), Some(0x99));
+        assert_eq!(Bb::bcd_encode(255u8), None);
+        assert_eq!(Bb::bcd_encode(1234u16), Some(0x1234));
+        assert_eq!(Bb::bcd_encode(10_000u16), None);
+        assert_eq!(Bb::bcd_encode(99_999_999u32), Some(0x9999_9999));
+        assert_eq!(Bb::bcd_encode(100_000_000u32), None);
+        for x in 0..=9999u16 {
+            assert_eq!(Bb::bcd_encode(x).and_then(Bb::bcd_decode), Some(x));
+        }
+    }
+
+    #[test]
+    fn hex_digits() {
+        for (n, (&upper, &lower)) in b"0123456789ABCDEF".iter().zip(b"0123456789abcdef").enumerate() {
+            assert_eq!(Bb::hex_to_nibble(upper), Some(n as u8));
+            assert_eq!(Bb::hex_to_nibble(lower), Some(n as u8));
+            assert_eq!(Bb::nibble_to_hex(n as u8), upper);
+            assert_eq!(Bb::nibble_to_hex_lower(n as u8), lower);
+        }
+        for b in [b'/', b':', b'@', b'G', b'`', b'g', b' ', 0, 0xff] {
+            assert_eq!(Bb::hex_to_nibble(b), None);
+        }
+        assert_eq!(Bb::nibble_to_hex(0x1f), b'F');
+    }
+
+    #[test]
+    fn hex() {
+        let mut buf = [b'-'; 40];
+        assert_eq!(Bb::hex(0x1234_abcdu32, &mut buf), 8);
+        assert_eq!(&buf[..9], b"1234ABCD-");
+        assert_eq!(Bb::hex_lower(0x1234_abcdu32, &mut buf), 8);
+        assert_eq!(&buf[..9], b"1234abcd-");
+        assert_eq!(Bb::hex(0x0au8, &mut buf), 2);
+        assert_eq!(&buf[..3], b"0A3");
+        assert_eq!(Bb::hex(-2i16, &mut buf), 4);
+        assert_eq!(&buf[..4], b"FFFE");
+        assert_eq!(Bb::hex_lower(0xdead_beefu64, &mut buf), 16);
+        assert_eq!(&buf[..16], b"00000000deadbeef");
+        assert_eq!(Bb::hex(u128::MAX - 1, &mut buf), 32);
+        assert_eq!(&buf[..32], b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE");
+        let mut exact = [0; 4];
+        assert_eq!(Bb::hex(0u16, &mut exact), 4);
+        assert_eq!(&exact, b"0000");
+    }
+
+    #[test]
+    fn parse_hex() {
+        assert_eq!(Bb::parse_hex::<u32>(b"1234ABCD"), Some(0x1234_abcd));
+        assert_eq!(Bb::parse_hex::<u32>(b"1234abcd"), Some(0x1234_abcd));
+        assert_eq!(Bb::parse_hex::<u8>(b"0a"), Some(10));
+        assert_eq!(Bb::parse_hex::<i16>(b"FFFE"), Some(-2));
+        assert_eq!(Bb::parse_hex::<u128>(&[b'f'; 32]), Some(u128::MAX));
+        assert_eq!(Bb::parse_hex::<u32>(b"1234abcg"), None);
+        assert_eq!(Bb::parse_hex::<u32>(b"+234abcd"), None);
+        assert_eq!(Bb::parse_hex::<u32>(b"1234abc"), None);
+        assert_eq!(Bb::parse_hex::<u32>(b"01234abcd"), None);
+        assert_eq!(Bb::parse_hex::<u8>(b""), None);
+        let mut buf = [0; 16];
+        for x in [0, 1, 0x0123_4567_89ab_cdef, u64::MAX] {
+            Bb::hex_lower(x, &mut buf);
+            assert_eq!(Bb::parse_hex::<u64>(&buf), Some(x));
+        }
+    }
+
+    #[test]
+    #[should_panic = "buffer is shorter than the hex digits"]
+    fn hex_short() {
+        let _ = Bb::hex(0u32, &mut [0; 7]);
+    }
+
+    #[test]
+    fn dec_digits() {
+        for (d, &b) in b"0123456789".iter().enumerate() {
+            assert_eq!(Bb::dec_to_digit(b), Some(d as u8));
+            assert_eq!(Bb::digit_to_dec(d as u8), b);
+        }
+        for b in (0..=u8::MAX).filter(|b| !b.is_ascii_digit()) {
+            assert_eq!(Bb::dec_to_digit(b), None);
+        }
+        const SEVEN: u8 = Bb::digit_to_dec(7);
+        assert_eq!(SEVEN, b'7');
+    }
+
+    #[test]
+    #[should_panic = "digit exceeds 9"]
+    fn digit_to_dec_overflow() {
+        let _ = Bb::digit_to_dec(10);
+    }
+}
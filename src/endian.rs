@@ -0,0 +1,470 @@
+use core::marker::PhantomData;
+
+use crate::AppliedTo;
+use crate::Bb;
+use crate::CastSigned;
+use crate::FromPdp;
+use crate::ReverseBits;
+use crate::ToPdp;
+use crate::RL;
+
+/// Big endian byte order.
+pub struct Big;
+
+/// Little endian byte order.
+pub struct Little;
+
+/// Byte order of the target platform.
+pub struct Native;
+
+#[doc(hidden)]
+pub trait ByteOrder: Copy {
+    fn swap_bytes(self) -> Self;
+
+    fn to_be(self) -> Self;
+
+    fn to_le(self) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_byte_order {
+    ($($T:ty)*) => {
+        $(
+            impl ByteOrder for $T {
+                fn swap_bytes(self) -> Self {
+                    <$T>::swap_bytes(self)
+                }
+
+                fn to_be(self) -> Self {
+                    <$T>::to_be(self)
+                }
+
+                fn to_le(self) -> Self {
+                    <$T>::to_le(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_order!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+/// Endianness marker: one of [Big], [Little] or [Native].
+pub trait Endian {
+    /// Converts `x` from native byte order to this byte order.
+    #[doc(hidden)]
+    fn convert<T: ByteOrder>(x: T) -> T;
+
+    /// Significance of the byte at memory index `i` of a `len` bytes long value in this byte order.
+    #[doc(hidden)]
+    fn significance(i: usize, len: usize) -> usize;
+
+    /// Whether this byte order is big endian: on the target platform, for [Native].
+    #[doc(hidden)]
+    const BIG: bool;
+}
+
+impl Endian for Big {
+    const BIG: bool = true;
+
+    fn convert<T: ByteOrder>(x: T) -> T {
+        x.to_be()
+    }
+
+    fn significance(i: usize, len: usize) -> usize {
+        len - 1 - i
+    }
+}
+
+impl Endian for Little {
+    const BIG: bool = false;
+
+    fn convert<T: ByteOrder>(x: T) -> T {
+        x.to_le()
+    }
+
+    fn significance(i: usize, _: usize) -> usize {
+        i
+    }
+}
+
+impl Endian for Native {
+    const BIG: bool = cfg!(target_endian = "big");
+
+    fn convert<T: ByteOrder>(x: T) -> T {
+        x
+    }
+
+    fn significance(i: usize, len: usize) -> usize {
+        match cfg!(target_endian = "big") {
+            true => Big::significance(i, len),
+            false => Little::significance(i, len),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait ByteLanes: Copy {
+    const BYTES: usize;
+
+    /// Byte of significance `k`, where 0 is the least significant byte.
+    fn lane(self, k: usize) -> u8;
+
+    fn with_lane(self, k: usize, b: u8) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! impl_byte_lanes {
+    ($($T:ty, $U:ty);*) => {
+        $(
+            impl ByteLanes for $T {
+                const BYTES: usize = core::mem::size_of::<$T>();
+
+                fn lane(self, k: usize) -> u8 {
+                    (self as $U >> (8 * k)) as u8
+                }
+
+                fn with_lane(self, k: usize, b: u8) -> $T {
+                    let shift = 8 * k;
+                    (self as $U & !(0xff << shift) | (b as $U) << shift) as $T
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_lanes!(i8, u8; u8, u8; i16, u16; u16, u16; i32, u32; u32, u32; i64, u64; u64, u64; i128, u128; u128, u128);
+
+impl Bb {
+    /// Byte at index `i` of `word` laid out in `E` byte order,
+    /// so `Bb::extract_byte::<Big>(x, i)` is `x.to_be_bytes()[i]`.
+    ///
+    /// ```
+    /// # use yabe::{Bb, Big, Little};
+    /// assert_eq!(Bb::extract_byte::<Big>(0x1122_3344u32, 0), 0x11);
+    /// assert_eq!(Bb::extract_byte::<Little>(0x1122_3344u32, 0), 0x44);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` isn't less than the size of `word` in bytes.
+    pub fn extract_byte<E: Endian>(word: impl ByteLanes, i: usize) -> u8 {
+        fn extract<E: Endian, T: ByteLanes>(word: T, i: usize) -> u8 {
+            assert!(i < T::BYTES, "byte index out of range");
+            word.lane(E::significance(i, T::BYTES))
+        }
+
+        extract::<E, _>(word, i)
+    }
+
+    /// Replaces the byte at index `i` of `word` laid out in `E` byte order with `b`, in place.
+    ///
+    /// ```
+    /// # use yabe::{Bb, Big, Little};
+    /// let mut word = 0x1122_3344u32;
+    /// Bb::deposit_byte::<Big>(&mut word, 0, 0xff);
+    /// assert_eq!(word, 0xff22_3344);
+    /// Bb::deposit_byte::<Little>(&mut word, 0, 0xee);
+    /// assert_eq!(word, 0xff22_33ee);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` isn't less than the size of `word` in bytes.
+    pub fn deposit_byte<E: Endian>(word: &mut impl ByteLanes, i: usize, b: u8) {
+        fn deposit<E: Endian, T: ByteLanes>(word: &mut T, i: usize, b: u8) {
+            assert!(i < T::BYTES, "byte index out of range");
+            *word = word.with_lane(E::significance(i, T::BYTES), b);
+        }
+
+        deposit::<E, _>(word, i, b)
+    }
+}
+
+impl Bb {
+    /// Swaps the bytes of every element in place.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut words = [0x1234u16, 0xabcd];
+    /// Bb::swap_slice(&mut words);
+    /// assert_eq!(words, [0x3412, 0xcdab]);
+    /// ```
+    pub fn swap_slice<T: ByteOrder>(xs: &mut [T]) {
+        for x in xs {
+            *x = x.swap_bytes();
+        }
+    }
+
+    /// Reverses the order of limbs and the bytes within each limb,
+    /// converting a multi-limb number between big and little endian layouts.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::reverse_limbs([0x0807_0605u32, 0x0403_0201]), [0x0102_0304, 0x0506_0708]);
+    /// ```
+    pub fn reverse_limbs<T: ByteOrder, const M: usize>(mut limbs: [T; M]) -> [T; M] {
+        limbs.reverse();
+        Bb::swap_slice(&mut limbs);
+        limbs
+    }
+
+    /// Converts every element in place from native to big endian byte order, or back.
+    pub fn to_be_slice<T: ByteOrder>(xs: &mut [T]) {
+        for x in xs {
+            *x = x.to_be();
+        }
+    }
+
+    /// Converts every element in place from native to little endian byte order, or back.
+    pub fn to_le_slice<T: ByteOrder>(xs: &mut [T]) {
+        for x in xs {
+            *x = x.to_le();
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ToEndian<E>(PhantomData<E>);
+
+impl<U> CastSigned<U> {
+    /// Converts from native byte order to `E`.
+    ///
+    /// ```
+    /// # use yabe::{Bb, Big, Endian};
+    /// fn encode<E: Endian>(x: u32) -> [u8; 4] {
+    ///     (x * Bb.endian::<E>()).to_ne_bytes()
+    /// }
+    ///
+    /// assert_eq!(encode::<Big>(0x01020304), [1, 2, 3, 4]);
+    /// ```
+    pub fn endian<E: Endian>(&self) -> RL<ToEndian<E>, U> {
+        RL(PhantomData)
+    }
+}
+
+impl<E: Endian, T: ByteOrder> AppliedTo<T> for ToEndian<E> {
+    type Output = T;
+
+    fn apply(src: T) -> T {
+        E::convert(src)
+    }
+}
+
+#[doc(hidden)]
+pub struct SwapIf<E>(PhantomData<E>);
+
+impl<U> CastSigned<U> {
+    /// Swaps bytes only if the target platform byte order differs from `E`,
+    /// through `to_be` or `to_le` so that no `#[cfg(target_endian)]` is needed.
+    ///
+    /// ```
+    /// # use yabe::{Bb, Big, Native};
+    /// assert_eq!(0x0102u16 * Bb.swap_if::<Big>(), 0x0102u16.to_be());
+    /// assert_eq!(0x0102u16 * Bb.swap_if::<Native>(), 0x0102);
+    /// ```
+    pub fn swap_if<E: Endian>(&self) -> RL<SwapIf<E>, U> {
+        RL(PhantomData)
+    }
+}
+
+impl<E: Endian, T: ByteOrder> AppliedTo<T> for SwapIf<E> {
+    type Output = T;
+
+    fn apply(src: T) -> T {
+        match E::BIG {
+            true => src.to_be(),
+            false => src.to_le(),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct RevIf<E>(PhantomData<E>);
+
+impl<U> CastSigned<U> {
+    /// Reverses the order of all bits if `E` is big endian, for bitstreams packed most significant bit first.
+    ///
+    /// ```
+    /// # use yabe::{Bb, Big, Little};
+    /// assert_eq!(0b0000_0001u8 * Bb.rev_if::<Big>(), 0b1000_0000);
+    /// assert_eq!(0b0000_0001u8 * Bb.rev_if::<Little>(), 0b0000_0001);
+    /// ```
+    pub fn rev_if<E: Endian>(&self) -> RL<RevIf<E>, U> {
+        RL(PhantomData)
+    }
+}
+
+impl<E: Endian, T> AppliedTo<T> for RevIf<E> where ReverseBits: AppliedTo<T, Output = T> {
+    type Output = T;
+
+    fn apply(src: T) -> T {
+        match E::BIG {
+            true => ReverseBits::apply(src),
+            false => src,
+        }
+    }
+}
+
+// PDP-endian: 16-bit words in big endian order, bytes within each word in little endian order
+#[doc(hidden)]
+macro_rules! impl_pdp {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for ToPdp {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    src.rotate_left(16).to_le()
+                }
+            }
+
+            impl AppliedTo<$T> for FromPdp {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    <$T>::from_le(src).rotate_left(16)
+                }
+            }
+        )*
+    };
+}
+
+impl_pdp!(i32 u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bb;
+
+    fn encode<E: Endian>(x: u32) -> [u8; 4] {
+        (x * Bb.endian::<E>()).to_ne_bytes()
+    }
+
+    #[test]
+    fn endian() {
+        assert_eq!(0x1234u16 * Bb.endian::<Big>(), 0x1234u16.to_be());
+        assert_eq!(0x1234u16 * Bb.endian::<Little>(), 0x1234u16.to_le());
+        assert_eq!(0x1234u16 * Bb.endian::<Native>(), 0x1234);
+        assert_eq!(-2i64 * Bb.endian::<Big>(), (-2i64).to_be());
+        assert_eq!(0x12u8 * Bb.e16.endian::<Big>(), 0x12u16.to_be());
+        assert_eq!(encode::<Big>(0x01020304), [1, 2, 3, 4]);
+        assert_eq!(encode::<Little>(0x01020304), [4, 3, 2, 1]);
+        assert_eq!(encode::<Native>(0x01020304), 0x01020304u32.to_ne_bytes());
+    }
+
+    #[test]
+    fn swap_slice() {
+        let src = [0x0102_0304u32, 0xdead_beef, 0, u32::MAX];
+        let mut xs = src;
+        Bb::swap_slice(&mut xs);
+        assert_eq!(xs, [0x0403_0201, 0xefbe_adde, 0, u32::MAX]);
+        Bb::swap_slice(&mut xs);
+        assert_eq!(xs, src);
+        Bb::to_be_slice(&mut xs);
+        assert_eq!(xs, src.map(u32::to_be));
+        Bb::to_be_slice(&mut xs);
+        assert_eq!(xs, src);
+        Bb::to_le_slice(&mut xs);
+        assert_eq!(xs, src.map(u32::to_le));
+        let mut bytes = [1u8, 2];
+        Bb::swap_slice(&mut bytes);
+        assert_eq!(bytes, [1, 2]);
+        let mut empty: [i64; 0] = [];
+        Bb::swap_slice(&mut empty);
+    }
+
+    #[test]
+    fn reverse_limbs() {
+        let wide = 0x0001_0203_0405_0607_0809_0a0b_0c0d_0e0fu128;
+        // least significant limb first
+        let le = [0x0c0d_0e0fu32, 0x0809_0a0b, 0x0405_0607, 0x0001_0203];
+        let be = Bb::reverse_limbs(le);
+        assert_eq!(be, [0x0302_0100, 0x0706_0504, 0x0b0a_0908, 0x0f0e_0d0c]);
+        let mut bytes = [0u8; 16];
+        for (chunk, limb) in bytes.chunks_exact_mut(4).zip(be) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        assert_eq!(bytes, wide.to_be_bytes());
+        assert_eq!(Bb::reverse_limbs(be), le);
+        assert_eq!(Bb::reverse_limbs([0u64; 0]), []);
+        assert_eq!(Bb::reverse_limbs([0x12u8, 0x34]), [0x34, 0x12]);
+    }
+
+    #[test]
+    fn pdp() {
+        assert_eq!((0x0a0b0c0du32 * Bb.pdp).to_ne_bytes(), [0x0b, 0x0a, 0x0d, 0x0c]);
+        assert_eq!((-2i32 * Bb.pdp).to_ne_bytes(), [0xff, 0xff, 0xfe, 0xff]);
+        for x in [0u32, 1, 0x0a0b0c0d, 0xdead_beef, u32::MAX] {
+            assert_eq!(x * Bb.pdp.unpdp, x);
+            assert_eq!(x * Bb.unpdp.pdp, x);
+        }
+        assert_eq!(i32::MIN * Bb.pdp.unpdp, i32::MIN);
+    }
+
+    #[test]
+    fn extract_deposit_byte() {
+        let x = 0x0102_0304_0506_0708u64;
+        for i in 0..8 {
+            assert_eq!(Bb::extract_byte::<Big>(x, i), x.to_be_bytes()[i]);
+            assert_eq!(Bb::extract_byte::<Little>(x, i), x.to_le_bytes()[i]);
+            assert_eq!(Bb::extract_byte::<Native>(x, i), x.to_ne_bytes()[i]);
+            assert_eq!(Bb::extract_byte::<Big>(x, i), Bb::extract_byte::<Little>(x, 7 - i));
+            let (mut be, mut word) = (x.to_be_bytes(), x);
+            be[i] = 0xee;
+            Bb::deposit_byte::<Big>(&mut word, i, 0xee);
+            assert_eq!(word, u64::from_be_bytes(be));
+            let (mut le, mut word) = (x.to_le_bytes(), x);
+            le[i] = 0xee;
+            Bb::deposit_byte::<Little>(&mut word, i, 0xee);
+            assert_eq!(word, u64::from_le_bytes(le));
+        }
+        assert_eq!(Bb::extract_byte::<Big>(-2i16, 1), 0xfe);
+        let mut word = -1i16;
+        Bb::deposit_byte::<Big>(&mut word, 0, 0x7f);
+        assert_eq!(word, 0x7fff);
+        let mut word = 0i8;
+        Bb::deposit_byte::<Little>(&mut word, 0, 0x80);
+        assert_eq!(word, i8::MIN);
+        let mut word = 0u128;
+        Bb::deposit_byte::<Big>(&mut word, 0, 1);
+        assert_eq!(word, 1 << 120);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extract_byte_out_of_range() {
+        let _ = Bb::extract_byte::<Big>(0u32, 4);
+    }
+
+    #[test]
+    fn rev_if() {
+        let x = 0x0102_0304u32;
+        assert_eq!(x * Bb.rev_if::<Big>(), x.reverse_bits());
+        assert_eq!(x * Bb.rev_if::<Little>(), x);
+        assert_eq!(x * Bb.rev_if::<Native>(), if cfg!(target_endian = "big") { x.reverse_bits() } else { x });
+        assert_eq!(-2i8 * Bb.rev_if::<Big>(), 0x7f);
+        assert_eq!(1u128 * Bb.rev_if::<Big>(), 1 << 127);
+        assert_eq!(0x01u8 * Bb.e16.rev_if::<Big>().t8, 0);
+    }
+
+    #[test]
+    fn swap_if() {
+        let x = 0x0102_0304_0506_0708u64;
+        assert_eq!(x * Bb.swap_if::<Big>(), x.to_be());
+        assert_eq!(x * Bb.swap_if::<Little>(), x.to_le());
+        assert_eq!(x * Bb.swap_if::<Native>(), x);
+        assert_eq!(-2i16 * Bb.swap_if::<Big>(), (-2i16).to_be());
+        assert_eq!(-2i16 * Bb.swap_if::<Little>(), (-2i16).to_le());
+        assert_eq!(0xabu8 * Bb.swap_if::<Big>(), 0xab);
+        assert_eq!(1u128 * Bb.swap_if::<Big>(), 1u128.to_be());
+        assert_eq!(x * Bb.e128.swap_if::<Little>().t64, x.to_le());
+        if cfg!(target_endian = "little") {
+            assert_eq!(x * Bb.swap_if::<Big>(), x.swap_bytes());
+            assert_eq!(x * Bb.swap_if::<Little>(), x);
+        } else {
+            assert_eq!(x * Bb.swap_if::<Big>(), x);
+            assert_eq!(x * Bb.swap_if::<Little>(), x.swap_bytes());
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Bb;
+
+/// Conversion between a fieldless enum and its integer representation.
+///
+/// With the `derive` feature, `#[derive(BbRepr)]` implements this for enums with an integer `#[repr]`,
+/// and lets their values start a chain, as in `MyEnum::A * Bb.e32`.
+pub trait BbRepr: Sized {
+    /// The integer type of the enum `#[repr]`.
+    type Repr;
+
+    /// Discriminant of the value.
+    fn to_repr(self) -> Self::Repr;
+
+    /// Value with the given discriminant, `None` if there's no such variant.
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
+}
+
+impl Bb {
+    /// Discriminant of an enum value. See [BbRepr].
+    pub fn to_repr<T: BbRepr>(x: T) -> T::Repr {
+        x.to_repr()
+    }
+
+    /// Enum value with the given discriminant, `None` if there's no such variant. See [BbRepr].
+    pub fn from_repr<T: BbRepr>(repr: T::Repr) -> Option<T> {
+        T::from_repr(repr)
+    }
+}
@@ -0,0 +1,208 @@
+use core::marker::PhantomData;
+
+use crate::AppliedTo;
+use crate::AppliedWith;
+use crate::CastSigned;
+use crate::CheckedTruncate16;
+use crate::CheckedTruncate32;
+use crate::CheckedTruncate64;
+use crate::CheckedTruncate8;
+use crate::Exact;
+use crate::ExtendsTo;
+use crate::RA;
+use crate::Truncate16;
+use crate::Truncate32;
+use crate::Truncate64;
+use crate::Truncate8;
+use crate::TruncatesTo;
+
+#[doc(hidden)]
+macro_rules! checked_applied_to {
+    ($Op:ty => $U:ty: $($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for $Op {
+                type Output = Option<$U>;
+
+                fn apply(src: $T) -> Option<$U> {
+                    <$U>::try_from(src).ok()
+                }
+            }
+        )*
+    };
+}
+
+checked_applied_to!(CheckedTruncate8 => i8: i16 i32 i64 i128);
+checked_applied_to!(CheckedTruncate8 => u8: u16 u32 u64 u128);
+checked_applied_to!(CheckedTruncate16 => i16: i32 i64 i128);
+checked_applied_to!(CheckedTruncate16 => u16: u32 u64 u128);
+checked_applied_to!(CheckedTruncate32 => i32: i64 i128);
+checked_applied_to!(CheckedTruncate32 => u32: u64 u128);
+checked_applied_to!(CheckedTruncate64 => i64: i128);
+checked_applied_to!(CheckedTruncate64 => u64: u128);
+
+/// Value marked by `.exact`, for the following truncation to check.
+#[doc(hidden)]
+pub struct Exactly<T>(T);
+
+impl<T> AppliedTo<T> for Exact {
+    type Output = Exactly<T>;
+
+    fn apply(src: T) -> Exactly<T> {
+        Exactly(src)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! exact_applied_to {
+    ($Op:ty => $U:ty: $($T:ty)*) => {
+        $(
+            impl AppliedTo<Exactly<$T>> for $Op {
+                type Output = $U;
+
+                fn apply(src: Exactly<$T>) -> $U {
+                    let res = src.0 as $U;
+                    debug_assert!(res as $T == src.0, "value doesn't fit in {}", stringify!($U));
+                    res
+                }
+            }
+        )*
+    };
+}
+
+exact_applied_to!(Truncate8 => i8: i16 i32 i64 i128);
+exact_applied_to!(Truncate8 => u8: u16 u32 u64 u128);
+exact_applied_to!(Truncate16 => i16: i32 i64 i128);
+exact_applied_to!(Truncate16 => u16: u32 u64 u128);
+exact_applied_to!(Truncate32 => i32: i64 i128);
+exact_applied_to!(Truncate32 => u32: u64 u128);
+exact_applied_to!(Truncate64 => i64: i128);
+exact_applied_to!(Truncate64 => u64: u128);
+
+impl<S, T> TruncatesTo<T> for Exactly<S> where S: TruncatesTo<T> + PartialEq + Copy, T: ExtendsTo<S> + Copy {
+    fn truncate(self) -> T {
+        let res = self.0.truncate();
+        debug_assert!(res.extend() == self.0, "value doesn't fit in {}", core::any::type_name::<T>());
+        res
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct AndThen<F>(F);
+
+impl<U> CastSigned<U> {
+    /// Continues a chain producing `Option` or `Result` with `f`, which is only called on success.
+    ///
+    /// For `Result`, the error of the chain is converted into the error of `f` with `From`, like `?` does.
+    ///
+    /// Chains don't know their input type, so the closure parameter usually needs a type annotation.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(200u16 * Bb.ct8.and_then(|b: u8| b.checked_mul(2)), None);
+    /// assert_eq!(100u16 * Bb.ct8.and_then(|b: u8| b.checked_mul(2)), Some(200));
+    /// assert_eq!(300u16 * Bb.ct8.and_then(|b: u8| b.checked_mul(2)), None);
+    /// ```
+    pub fn and_then<F>(&self, f: F) -> RA<AndThen<F>, U> {
+        RA(AndThen(f), PhantomData)
+    }
+}
+
+impl<T, R, F: FnOnce(T) -> Option<R>> AppliedWith<Option<T>> for AndThen<F> {
+    type Output = Option<R>;
+
+    fn apply_with(self, src: Option<T>) -> Option<R> {
+        src.and_then(self.0)
+    }
+}
+
+impl<T, E, R, E2: From<E>, F: FnOnce(T) -> Result<R, E2>> AppliedWith<Result<T, E>> for AndThen<F> {
+    type Output = Result<R, E2>;
+
+    fn apply_with(self, src: Result<T, E>) -> Result<R, E2> {
+        src.map_err(E2::from).and_then(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bb;
+
+    #[test]
+    fn checked_truncate() {
+        assert_eq!(300u16 * Bb.ct8, None);
+        assert_eq!(255u16 * Bb.ct8, Some(255));
+        assert_eq!(-128i32 * Bb.ct8, Some(-128));
+        assert_eq!(-129i32 * Bb.ct8, None);
+        assert_eq!(u64::MAX * Bb.ct32, None);
+        assert_eq!(-1i128 * Bb.ct64, Some(-1));
+        assert_eq!(-1i16 * Bb.u.ct8, None);
+        assert_eq!(0x7fffi64 * Bb.ct16, Some(0x7fff));
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(255u16 * Bb.exact.t8, 255);
+        assert_eq!(-128i64 * Bb.exact.t8, -128);
+        assert_eq!(-1i64 * Bb.exact.t32.exact.t16, -1);
+        assert_eq!(0xffu32 * Bb.exact.truncate_to::<u8>(), 0xff);
+        assert_eq!(-1i32 * Bb.exact.truncate_to::<i8>(), -1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "value doesn't fit in u8"]
+    fn exact_lossy() {
+        let _ = 256u16 * Bb.exact.t8;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "value doesn't fit in i8"]
+    fn exact_lossy_sign() {
+        let _ = 128i32 * Bb.exact.t8;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "value doesn't fit in u8"]
+    fn exact_lossy_truncate_to() {
+        let _ = -1i32 * Bb.exact.truncate_to::<u8>();
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn exact_release() {
+        assert_eq!(256u16 * Bb.exact.t8, 256u16 * Bb.t8);
+        assert_eq!(128i32 * Bb.exact.t8, -128);
+        assert_eq!(-1i32 * Bb.exact.truncate_to::<u8>(), 0xff);
+    }
+
+    #[test]
+    fn and_then() {
+        assert_eq!(300u16 * Bb.ct8.and_then(|b: u8| Some(b.reverse_bits())), None);
+        assert_eq!(3u16 * Bb.ct8.and_then(|b: u8| Some(b.reverse_bits())), Some(0xc0));
+        assert_eq!(100i64 * Bb.ct8.and_then(|b: i8| b.checked_add(27)), Some(127));
+        assert_eq!(100i64 * Bb.ct8.and_then(|b: i8| b.checked_add(28)), None);
+        assert_eq!(-1i32 * Bb.ct16.and_then(|x: i16| Some(x * Bb.u.e32)), Some(0xffff));
+        assert_eq!(5u32 * Bb.ct8.and_then(Some), Some(5u8));
+    }
+
+    #[test]
+    fn and_then_result() {
+        #[derive(Debug, PartialEq)]
+        struct Wide(u8);
+
+        impl From<u8> for Wide {
+            fn from(e: u8) -> Self {
+                Wide(e)
+            }
+        }
+
+        let halve = |x: u32| if x.is_multiple_of(2) { Ok(x / 2) } else { Err(Wide(0xff)) };
+        assert_eq!(AndThen(halve).apply_with(Ok::<u32, u8>(6)), Ok(3));
+        assert_eq!(AndThen(halve).apply_with(Ok::<u32, u8>(5)), Err(Wide(0xff)));
+        assert_eq!(AndThen(halve).apply_with(Err::<u32, u8>(1)), Err(Wide(1)));
+    }
+}
@@ -0,0 +1,637 @@
+use core::marker::PhantomData;
+
+use crate::AppliedTo;
+use crate::Bb;
+use crate::TruncatesTo;
+use crate::CastSigned;
+use crate::RL;
+
+impl Bb {
+    /// Concatenates `(value, bit_len)` fields into a word, the first field ending up most significant.
+    ///
+    /// The last field occupies the lowest bits, and bits above the total length are zero.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::pack(&[(0b1, 1), (0x2a, 7), (0xbeef, 16)]), 0xaa_beef);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total length exceeds 64 bits, or if a value doesn't fit into its length.
+    pub fn pack(fields: &[(u64, u32)]) -> u64 {
+        let mut total = 0u32;
+        let mut word = 0u64;
+        for &(value, len) in fields {
+            total = total.saturating_add(len);
+            assert!(total <= u64::BITS, "packed fields exceed 64 bits");
+            assert!(len == u64::BITS || value >> len == 0, "field value doesn't fit into its bit length");
+            word = word.checked_shl(len).unwrap_or(0) | value;
+        }
+        word
+    }
+
+    /// Splits `word` into fields of bit lengths `lens`, the inverse of [pack](Bb::pack).
+    ///
+    /// Fields are peeled most significant first, so `out[i]` receives the field of length `lens[i]`, right aligned.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut out = [0; 3];
+    /// Bb::unpack_into(0xaa_beef, &[1, 7, 16], &mut out);
+    /// assert_eq!(out, [0b1, 0x2a, 0xbeef]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lens` and `out` differ in length, or if the total length exceeds 64 bits.
+    pub fn unpack_into(word: u64, lens: &[u32], out: &mut [u64]) {
+        assert_eq!(lens.len(), out.len(), "field count doesn't match output length");
+        let total = lens.iter().fold(0u32, |total, &len| total.saturating_add(len));
+        assert!(total <= u64::BITS, "packed fields exceed 64 bits");
+        let mut word = word;
+        for (&len, o) in lens.iter().zip(out).rev() {
+            *o = word & u64::MAX.checked_shr(u64::BITS - len).unwrap_or(0);
+            word = word.checked_shr(len).unwrap_or(0);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait PackBools: Sized {
+    fn pack_bools(flags: &[bool]) -> Self;
+
+    fn unpack_bools_into(self, out: &mut [bool]);
+}
+
+#[doc(hidden)]
+macro_rules! impl_pack_bools {
+    ($($T:ty)*) => {
+        $(
+            impl PackBools for $T {
+                fn pack_bools(flags: &[bool]) -> $T {
+                    assert!(flags.len() <= <$T>::BITS as usize, "more flags than bits in the word");
+                    flags.iter().rev().fold(0, |word, &f| word << 1 | f as $T)
+                }
+
+                fn unpack_bools_into(self, out: &mut [bool]) {
+                    assert!(out.len() <= <$T>::BITS as usize, "more flags than bits in the word");
+                    for (i, o) in out.iter_mut().enumerate() {
+                        *o = self >> i & 1 != 0;
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_pack_bools!(u8 u16 u32 u64);
+
+impl Bb {
+    /// Packs up to 8 flags into a `u8`, `flags[0]` becoming the least significant bit.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::pack_bools_u8(&[true, false, true]), 0b101);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 8 flags.
+    pub fn pack_bools_u8(flags: &[bool]) -> u8 {
+        u8::pack_bools(flags)
+    }
+
+    /// Packs up to 16 flags into a `u16`, `flags[0]` becoming the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 16 flags.
+    pub fn pack_bools_u16(flags: &[bool]) -> u16 {
+        u16::pack_bools(flags)
+    }
+
+    /// Packs up to 32 flags into a `u32`, `flags[0]` becoming the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 32 flags.
+    pub fn pack_bools_u32(flags: &[bool]) -> u32 {
+        u32::pack_bools(flags)
+    }
+
+    /// Packs up to 64 flags into a `u64`, `flags[0]` becoming the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 64 flags.
+    pub fn pack_bools_u64(flags: &[bool]) -> u64 {
+        u64::pack_bools(flags)
+    }
+
+    /// Unpacks the low `out.len()` bits of `word` into flags, least significant bit first.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut flags = [false; 4];
+    /// Bb::unpack_bools_into(0b0101u8, &mut flags);
+    /// assert_eq!(flags, [true, false, true, false]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is longer than the bit width of `T`.
+    pub fn unpack_bools_into<T: PackBools>(word: T, out: &mut [bool]) {
+        word.unpack_bools_into(out)
+    }
+}
+
+#[doc(hidden)]
+pub trait SignExtend {
+    type Signed;
+
+    fn sext(self, bits: u32) -> Self::Signed;
+}
+
+#[doc(hidden)]
+macro_rules! impl_sign_extend {
+    ($($T:ty => $S:ty),*) => {
+        $(
+            impl SignExtend for $T {
+                type Signed = $S;
+
+                fn sext(self, bits: u32) -> $S {
+                    assert!(bits <= <$S>::BITS, "bit count exceeds the bit width");
+                    match <$S>::BITS - bits {
+                        <$S>::BITS => 0,
+                        shift => (self as $S) << shift >> shift,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_sign_extend!(i8 => i8, u8 => i8, i16 => i16, u16 => i16, i32 => i32, u32 => i32, i64 => i64, u64 => i64, i128 => i128, u128 => i128);
+
+impl Bb {
+    /// Sign extends the low `bits` bits of `x`, treating bit `bits - 1` as the sign,
+    /// into the signed type of same width.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::sext(0x1fffu16, 13), -1i16);
+    /// assert_eq!(Bb::sext(0x0fffu16, 13), 0xfff);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than the bit width of `T`.
+    pub fn sext<T: SignExtend>(x: T, bits: u32) -> T::Signed {
+        x.sext(bits)
+    }
+}
+
+impl Bb {
+    /// Reinterprets the low `n` bits of `x` as an `n`-bit two's complement value, wrapped
+    /// into that range, and sign extends it to `i32`. Bits above the low `n` are masked off first.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::wrap_signed(0x1f, 5), -1);
+    /// assert_eq!(Bb::wrap_signed(0x2f, 5), 0xf);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than 32.
+    pub fn wrap_signed(x: u32, n: u32) -> i32 {
+        Bb::zext(x, n).sext(n)
+    }
+
+    /// Encodes `x` as an `n`-bit two's complement field in the low `n` bits, the inverse of
+    /// [wrap_signed](Bb::wrap_signed). Produces `None` if `x` isn't in the range of `n` signed bits.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::pack_signed(-1, 5), Some(0x1f));
+    /// assert_eq!(Bb::pack_signed(16, 5), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than 32.
+    pub fn pack_signed(x: i32, n: u32) -> Option<u32> {
+        let field = Bb::zext(x, n);
+        (field.sext(n) == x).then_some(field)
+    }
+
+    /// Clamps `x` into the range of an `n`-bit unsigned field, `0..=2^n - 1`, and encodes it in the low `n` bits.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::clamp_encode_unsigned(-7, 10), 0);
+    /// assert_eq!(Bb::clamp_encode_unsigned(5000, 10), 0x3ff);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than 32.
+    pub fn clamp_encode_unsigned(x: i32, n: u32) -> u32 {
+        x.max(0) as u32 * Bb.clampbits(n)
+    }
+
+    /// Encodes `x` as an `n`-bit sign-magnitude field: bit `n - 1` is the sign and the low `n - 1` bits
+    /// are the magnitude, saturated to `2^(n - 1) - 1`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::encode_sign_mag(-5, 8), 0x85);
+    /// assert_eq!(Bb::encode_sign_mag(-500, 8), 0xff);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` isn't in `1..=32`.
+    pub fn encode_sign_mag(x: i32, n: u32) -> u32 {
+        assert!((1..=u32::BITS).contains(&n), "field width must be in 1..=32");
+        let sign = ((x < 0) as u32) << (n - 1);
+        sign | (x.unsigned_abs() * Bb.clampbits(n - 1))
+    }
+
+    /// Decodes the low `n` bits of `field` as a sign-magnitude value, the inverse of
+    /// [encode_sign_mag](Bb::encode_sign_mag). Negative zero decodes to zero.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::decode_sign_mag(0x85, 8), -5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` isn't in `1..=32`.
+    pub fn decode_sign_mag(field: u32, n: u32) -> i32 {
+        assert!((1..=u32::BITS).contains(&n), "field width must be in 1..=32");
+        let mag = Bb::zext(field, n - 1) as i32;
+        match field >> (n - 1) & 1 {
+            0 => mag,
+            _ => -mag,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait ZeroExtend {
+    type Unsigned;
+
+    fn zext(self, bits: u32) -> Self::Unsigned;
+}
+
+#[doc(hidden)]
+macro_rules! impl_zero_extend {
+    ($($T:ty => $U:ty),*) => {
+        $(
+            impl ZeroExtend for $T {
+                type Unsigned = $U;
+
+                fn zext(self, bits: u32) -> $U {
+                    assert!(bits <= <$U>::BITS, "bit count exceeds the bit width");
+                    self as $U & <$U>::MAX.checked_shr(<$U>::BITS - bits).unwrap_or(0)
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_extend!(i8 => u8, u8 => u8, i16 => u16, u16 => u16, i32 => u32, u32 => u32, i64 => u64, u64 => u64, i128 => u128, u128 => u128);
+
+impl Bb {
+    /// Keeps the low `bits` bits of `x`, clearing the rest, as the unsigned type of same width.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::zext(-1i16, 13), 0x1fffu16);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than the bit width of `T`.
+    pub fn zext<T: ZeroExtend>(x: T, bits: u32) -> T::Unsigned {
+        x.zext(bits)
+    }
+}
+
+/// Bit count `N`, mapped to the smallest unsigned type holding `N` bits.
+#[doc(hidden)]
+pub struct Bits<const N: u32>;
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(message = "no unsigned type holds `{Self}`", label = "bit count must be in 1..=128")]
+pub trait Fit {
+    type Type;
+
+    fn trunc<S: TruncatesTo<Self::Type>>(src: S) -> Self::Type;
+}
+
+#[doc(hidden)]
+macro_rules! impl_fit {
+    ($($T:ty: $($N:literal)*;)*) => {
+        $($(
+            impl Fit for Bits<$N> {
+                type Type = $T;
+
+                fn trunc<S: TruncatesTo<$T>>(src: S) -> $T {
+                    src.truncate() & <$T>::MAX >> (<$T>::BITS - $N)
+                }
+            }
+        )*)*
+    };
+}
+
+impl_fit! {
+    u8: 1 2 3 4 5 6 7 8;
+    u16: 9 10 11 12 13 14 15 16;
+    u32: 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32;
+    u64: 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48
+        49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64;
+    u128: 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80
+        81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96
+        97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112
+        113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128;
+}
+
+#[doc(hidden)]
+pub struct Trunc<const N: u32>;
+
+impl<U> CastSigned<U> {
+    /// Keeps the low `N` bits as the smallest unsigned type holding them, from `u8` to `u128`.
+    /// `N` must not exceed the bit width of the source.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let x: u16 = 0xabcdu32 * Bb.trunc::<12>();
+    /// assert_eq!(x, 0xbcd);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use yabe::Bb;
+    /// let _ = 5u8 * Bb.trunc::<9>();
+    /// ```
+    pub fn trunc<const N: u32>(&self) -> RL<Trunc<N>, U> {
+        RL(PhantomData)
+    }
+}
+
+impl<S, const N: u32> AppliedTo<S> for Trunc<N>
+where
+    Bits<N>: Fit,
+    S: TruncatesTo<<Bits<N> as Fit>::Type>,
+{
+    type Output = <Bits<N> as Fit>::Type;
+
+    fn apply(src: S) -> Self::Output {
+        Bits::<N>::trunc(src)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    #[test]
+    fn pack() {
+        let (a, b, c) = (0b101u64, 0x3ffu64, 0x12u64);
+        assert_eq!(Bb::pack(&[(a, 3), (b, 10), (c, 5)]), a << 15 | b << 5 | c);
+        assert_eq!(Bb::pack(&[]), 0);
+        assert_eq!(Bb::pack(&[(0, 0), (1, 1)]), 1);
+        assert_eq!(Bb::pack(&[(u64::MAX, 64)]), u64::MAX);
+        assert_eq!(Bb::pack(&[(1, 1), (0, 63)]), 1 << 63);
+        assert_eq!(Bb::pack(&[(0xffff_ffff, 32), (0, 32)]), 0xffff_ffff_0000_0000);
+    }
+
+    #[test]
+    fn unpack_into() {
+        let fields = [(0b101u64, 3), (0x3ff, 10), (0, 0), (0x12, 5)];
+        let lens = fields.map(|(_, len)| len);
+        let mut out = [0; 4];
+        Bb::unpack_into(Bb::pack(&fields), &lens, &mut out);
+        assert_eq!(out, fields.map(|(value, _)| value));
+        let mut out = [0; 2];
+        Bb::unpack_into(0x8000_0000_0000_0001, &[1, 63], &mut out);
+        assert_eq!(out, [1, 1]);
+        Bb::unpack_into(u64::MAX, &[64, 0], &mut out);
+        assert_eq!(out, [u64::MAX, 0]);
+        // bits above the total length are ignored
+        Bb::unpack_into(0xff, &[2, 2], &mut out);
+        assert_eq!(out, [3, 3]);
+        Bb::unpack_into(0xff, &[], &mut []);
+    }
+
+    #[test]
+    fn pack_bools() {
+        assert_eq!(Bb::pack_bools_u8(&[]), 0);
+        assert_eq!(Bb::pack_bools_u8(&[true; 8]), u8::MAX);
+        assert_eq!(Bb::pack_bools_u16(&[false, true]), 0b10);
+        let flags: [bool; 64] = core::array::from_fn(|i| i % 3 == 0);
+        let word = Bb::pack_bools_u64(&flags);
+        assert_eq!(word, (0..64).step_by(3).fold(0, |w, i| w | 1 << i));
+        let mut out = [false; 64];
+        Bb::unpack_bools_into(word, &mut out);
+        assert_eq!(out, flags);
+        let mut out = [true; 20];
+        Bb::unpack_bools_into(Bb::pack_bools_u32(&flags[..20]), &mut out);
+        assert_eq!(out, flags[..20]);
+        // bits past the output length are ignored
+        let mut out = [false; 2];
+        Bb::unpack_bools_into(0xfeu8, &mut out);
+        assert_eq!(out, [false, true]);
+    }
+
+    #[test]
+    #[should_panic = "more flags than bits in the word"]
+    fn pack_bools_overlong() {
+        let _ = Bb::pack_bools_u8(&[false; 9]);
+    }
+
+    #[test]
+    #[should_panic = "more flags than bits in the word"]
+    fn unpack_bools_overlong() {
+        Bb::unpack_bools_into(0u16, &mut [false; 17]);
+    }
+
+    #[test]
+    fn sext() {
+        assert_eq!(Bb::sext(0x1000u16, 13), -0x1000);
+        assert_eq!(Bb::sext(0x1ffeu16, 13), -2);
+        assert_eq!(Bb::sext(0x0123u16, 13), 0x123);
+        // bits above the field are ignored
+        assert_eq!(Bb::sext(0xe123u16, 13), 0x123);
+        assert_eq!(Bb::sext(0xffffu16, 16), -1);
+        assert_eq!(Bb::sext(0xffffu16, 0), 0);
+        assert_eq!(Bb::sext(1u8, 1), -1);
+        assert_eq!(Bb::sext(0x7fi8, 7), -1);
+        assert_eq!(Bb::sext(0x8000_0000u64, 32), -0x8000_0000);
+        assert_eq!(Bb::sext(u128::MAX >> 1, 127), -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sext_overlong() {
+        let _ = Bb::sext(0u16, 17);
+    }
+
+    #[test]
+    fn zext() {
+        assert_eq!(Bb::zext(0xffffu16, 13), 0x1fff);
+        assert_eq!(Bb::zext(0xe123u16, 13), 0x0123);
+        assert_eq!(Bb::zext(0xe123u16, 16), 0xe123);
+        assert_eq!(Bb::zext(0xe123u16, 0), 0);
+        assert_eq!(Bb::zext(-1i8, 1), 1u8);
+        assert_eq!(Bb::zext(i64::MIN, 63), 0);
+        assert_eq!(Bb::zext(-1i128, 100), u128::MAX >> 28);
+        for bits in 0..=16 {
+            let x = Bb::sext(0xa5a5u16, bits);
+            assert_eq!(Bb::zext(x, bits), Bb::zext(0xa5a5u16, bits));
+        }
+    }
+
+    #[test]
+    fn wrap_signed() {
+        assert_eq!(Bb::wrap_signed(0x10, 5), -16);
+        assert_eq!(Bb::wrap_signed(0x1e, 5), -2);
+        assert_eq!(Bb::wrap_signed(0x0f, 5), 15);
+        assert_eq!(Bb::wrap_signed(0xffff_ffe0, 5), 0);
+        assert_eq!(Bb::wrap_signed(0xffff_fff0, 5), -16);
+        assert_eq!(Bb::wrap_signed(0x8000_0000, 32), i32::MIN);
+        assert_eq!(Bb::wrap_signed(0x7fff_ffff, 32), i32::MAX);
+        assert_eq!(Bb::wrap_signed(1, 1), -1);
+        assert_eq!(Bb::wrap_signed(u32::MAX, 0), 0);
+    }
+
+    #[test]
+    fn pack_signed() {
+        assert_eq!(Bb::pack_signed(-3, 5), Some(0b11101));
+        assert_eq!(Bb::pack_signed(-16, 5), Some(0x10));
+        assert_eq!(Bb::pack_signed(15, 5), Some(0xf));
+        assert_eq!(Bb::pack_signed(16, 5), None);
+        assert_eq!(Bb::pack_signed(-17, 5), None);
+        assert_eq!(Bb::pack_signed(0, 0), Some(0));
+        assert_eq!(Bb::pack_signed(-1, 0), None);
+        assert_eq!(Bb::pack_signed(-1, 1), Some(1));
+        assert_eq!(Bb::pack_signed(i32::MIN, 32), Some(0x8000_0000));
+        assert_eq!(Bb::pack_signed(i32::MIN, 31), None);
+        for x in -16..16 {
+            assert_eq!(Bb::pack_signed(x, 5).map(|f| Bb::wrap_signed(f, 5)), Some(x));
+        }
+    }
+
+    #[test]
+    fn sign_mag() {
+        assert_eq!(Bb::encode_sign_mag(0, 8), 0);
+        assert_eq!(Bb::encode_sign_mag(5, 8), 5);
+        assert_eq!(Bb::encode_sign_mag(127, 8), 0x7f);
+        assert_eq!(Bb::encode_sign_mag(128, 8), 0x7f);
+        assert_eq!(Bb::encode_sign_mag(-127, 8), 0xff);
+        assert_eq!(Bb::encode_sign_mag(i32::MIN, 8), 0xff);
+        assert_eq!(Bb::encode_sign_mag(i32::MIN, 32), u32::MAX);
+        assert_eq!(Bb::encode_sign_mag(i32::MAX, 32), i32::MAX as u32);
+        assert_eq!(Bb::encode_sign_mag(-3, 1), 1);
+        assert_eq!(Bb::decode_sign_mag(1, 1), 0);
+        assert_eq!(Bb::decode_sign_mag(0x80, 8), 0);
+        assert_eq!(Bb::decode_sign_mag(0xff, 8), -127);
+        // bits above the field are ignored
+        assert_eq!(Bb::decode_sign_mag(0x1_0085, 8), -5);
+        assert_eq!(Bb::decode_sign_mag(u32::MAX, 32), -i32::MAX);
+        for n in [2, 5, 12, 31, 32] {
+            let max = i32::MAX >> (32 - n);
+            for x in [-max, -max / 2, -1, 0, 1, max / 3, max] {
+                assert_eq!(Bb::decode_sign_mag(Bb::encode_sign_mag(x, n), n), x);
+            }
+            assert_eq!(Bb::decode_sign_mag(Bb::encode_sign_mag(max.saturating_add(1), n), n), max);
+        }
+    }
+
+    #[test]
+    #[should_panic = "field width must be in 1..=32"]
+    fn sign_mag_empty() {
+        let _ = Bb::encode_sign_mag(0, 0);
+    }
+
+    #[test]
+    fn clamp_encode_unsigned() {
+        assert_eq!(Bb::clamp_encode_unsigned(-1, 8), 0);
+        assert_eq!(Bb::clamp_encode_unsigned(i32::MIN, 8), 0);
+        assert_eq!(Bb::clamp_encode_unsigned(256, 8), 0xff);
+        assert_eq!(Bb::clamp_encode_unsigned(i32::MAX, 8), 0xff);
+        assert_eq!(Bb::clamp_encode_unsigned(200, 8), 200);
+        assert_eq!(Bb::clamp_encode_unsigned(0, 8), 0);
+        assert_eq!(Bb::clamp_encode_unsigned(5, 0), 0);
+        assert_eq!(Bb::clamp_encode_unsigned(i32::MAX, 32), i32::MAX as u32);
+        assert_eq!(Bb::clamp_encode_unsigned(i32::MAX, 31), i32::MAX as u32);
+        assert_eq!(Bb::clamp_encode_unsigned(i32::MAX, 30), (1 << 30) - 1);
+    }
+
+    #[test]
+    #[should_panic = "bit count exceeds the bit width"]
+    fn clamp_encode_unsigned_overlong() {
+        let _ = Bb::clamp_encode_unsigned(0, 33);
+    }
+
+    #[test]
+    #[should_panic = "bit count exceeds the bit width"]
+    fn pack_signed_overlong() {
+        let _ = Bb::pack_signed(0, 33);
+    }
+
+    #[test]
+    #[should_panic = "bit count exceeds the bit width"]
+    fn wrap_signed_overlong() {
+        let _ = Bb::wrap_signed(0, 33);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zext_overlong() {
+        let _ = Bb::zext(0u8, 9);
+    }
+
+    #[test]
+    fn trunc() {
+        fn is<T>(_: &T) -> core::any::TypeId where T: 'static {
+            core::any::TypeId::of::<T>()
+        }
+
+        let x = 0xabcdu32 * Bb.trunc::<12>();
+        assert_eq!(is(&x), is(&0u16));
+        assert_eq!(x, 0xbcd);
+        let x = u64::MAX * Bb.trunc::<40>();
+        assert_eq!(is(&x), is(&0u64));
+        assert_eq!(x, 0xff_ffff_ffff);
+        assert_eq!(0xffu8 * Bb.trunc::<1>(), 1u8);
+        assert_eq!(0x1ffu16 * Bb.trunc::<8>(), 0xffu8);
+        assert_eq!(0x1ffu16 * Bb.trunc::<9>(), 0x1ffu16);
+        assert_eq!(-1i8 * Bb.trunc::<8>(), u8::MAX);
+        assert_eq!(-1i8 * Bb.trunc::<3>(), 0b111u8);
+        assert_eq!(-1i128 * Bb.trunc::<128>(), u128::MAX);
+        assert_eq!(-1i128 * Bb.trunc::<65>(), u128::MAX >> 63);
+        assert_eq!(0x1_2345u32 * Bb.trunc::<17>().t16, 0x2345u16);
+    }
+
+    #[test]
+    #[should_panic = "field count doesn't match"]
+    fn unpack_into_mismatch() {
+        Bb::unpack_into(0, &[1, 2], &mut [0; 3]);
+    }
+
+    #[test]
+    #[should_panic = "packed fields exceed 64 bits"]
+    fn pack_overlong() {
+        let _ = Bb::pack(&[(0, 32), (0, 32), (0, 1)]);
+    }
+
+    #[test]
+    #[should_panic = "field value doesn't fit"]
+    fn pack_wide_value() {
+        let _ = Bb::pack(&[(0b100, 2)]);
+    }
+}
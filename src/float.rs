@@ -0,0 +1,419 @@
+use crate::AppliedTo;
+use crate::Bb;
+use crate::BitcastError;
+use crate::F16Rne;
+use crate::F16Rtz;
+use crate::IsFinite;
+use crate::IsNan;
+use crate::IsSubnormal;
+
+/// NaN policy of [Bb::f2i] converting NaN to zero, like `as` does.
+pub struct NanZero;
+
+/// NaN policy of [Bb::f2i] reporting NaN as [BitcastError::Nan].
+pub struct NanError;
+
+/// NaN policy of [Bb::f2i]: one of [NanZero] or [NanError].
+pub trait NanPolicy {
+    /// Result of the conversion to `T`.
+    type Output<T>;
+
+    #[doc(hidden)]
+    fn convert<T, F: FloatToInt<T>>(x: F) -> Self::Output<T>;
+}
+
+impl NanPolicy for NanZero {
+    type Output<T> = T;
+
+    fn convert<T, F: FloatToInt<T>>(x: F) -> T {
+        x.saturate()
+    }
+}
+
+impl NanPolicy for NanError {
+    type Output<T> = Result<T, BitcastError>;
+
+    fn convert<T, F: FloatToInt<T>>(x: F) -> Result<T, BitcastError> {
+        match x.is_nan() {
+            true => Err(BitcastError::Nan),
+            false => Ok(x.saturate()),
+        }
+    }
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`f2i` requires an `f32` or `f64` input and an integer target, not `{Self}` to `{T}`",
+    label = "can't convert `{Self}` to `{T}`",
+    note = "to convert integer bits, reinterpret them first with `f32::from_bits` or `f64::from_bits`"
+)]
+pub trait FloatToInt<T>: Copy {
+    fn is_nan(self) -> bool;
+
+    /// Rounds towards zero and clamps into the range of `T`, mapping NaN to zero.
+    fn saturate(self) -> T;
+}
+
+#[doc(hidden)]
+macro_rules! impl_float_to_int {
+    ($($F:ty)* => $T:tt) => {
+        $(
+            impl_float_to_int!(@ $F => $T);
+        )*
+    };
+    (@ $F:ty => [$($T:ty)*]) => {
+        $(
+            impl FloatToInt<$T> for $F {
+                fn is_nan(self) -> bool {
+                    <$F>::is_nan(self)
+                }
+
+                fn saturate(self) -> $T {
+                    self as $T
+                }
+            }
+        )*
+    };
+}
+
+impl_float_to_int!(f32 f64 => [i8 u8 i16 u16 i32 u32 i64 u64 i128 u128]);
+
+impl Bb {
+    /// Converts a float to the integer type `T`, rounding towards zero and saturating out of range values.
+    ///
+    /// The policy `P` decides what happens to NaN: [NanZero] maps it to zero like `as`,
+    /// while [NanError] makes the conversion fail instead of hiding the NaN.
+    ///
+    /// ```
+    /// # use yabe::{Bb, BitcastError, NanError, NanZero};
+    /// assert_eq!(Bb::f2i::<u8, NanZero>(300.5f32), 255);
+    /// assert_eq!(Bb::f2i::<u8, NanZero>(f32::NAN), 0);
+    /// assert_eq!(Bb::f2i::<u8, NanError>(-3.5f64), Ok(0));
+    /// assert_eq!(Bb::f2i::<u8, NanError>(f64::NAN), Err(BitcastError::Nan));
+    /// ```
+    pub fn f2i<T, P: NanPolicy>(x: impl FloatToInt<T>) -> P::Output<T> {
+        P::convert(x)
+    }
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`ulps` requires `f32` or `f64` inputs, not `{Self}`",
+    label = "not a float",
+    note = "to compare integer bits, reinterpret them first with `f32::from_bits` or `f64::from_bits`"
+)]
+pub trait Ulps: Copy {
+    fn ulps(self, to: Self) -> Result<i64, BitcastError>;
+}
+
+#[doc(hidden)]
+macro_rules! impl_ulps {
+    ($($F:ty: $S:ty),*) => {
+        $(
+            impl Ulps for $F {
+                fn ulps(self, to: $F) -> Result<i64, BitcastError> {
+                    // maps floats to integers of the same order, both zeros to 0
+                    fn key(x: $F) -> $S {
+                        match x.to_bits() as $S {
+                            bits if bits < 0 => <$S>::MIN - bits,
+                            bits => bits,
+                        }
+                    }
+
+                    if self.is_nan() || to.is_nan() {
+                        return Err(BitcastError::Nan);
+                    }
+                    i64::try_from(key(to) as i128 - key(self) as i128).map_err(|_| match key(to) > key(self) {
+                        true => BitcastError::Overflow { target: "i64" },
+                        false => BitcastError::Underflow { target: "i64" },
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_ulps!(f32: i32, f64: i64);
+
+impl Bb {
+    /// Signed distance from `a` to `b` in units in the last place: the number of representable
+    /// floats between them, positive if `b` is greater. Both zeros are the same float,
+    /// so the distance across zero is the sum of the distances to it.
+    ///
+    /// Fails with [BitcastError::Nan] if either is NaN. For `f64` the distance between floats of
+    /// opposite signs and huge magnitudes may not fit `i64`, failing with [BitcastError::Overflow]
+    /// or [BitcastError::Underflow].
+    ///
+    /// ```
+    /// # use yabe::{Bb, BitcastError};
+    /// assert_eq!(Bb::ulps(1.0f32, 1.0 + f32::EPSILON), Ok(1));
+    /// assert_eq!(Bb::ulps(f32::from_bits(1), -f32::from_bits(1)), Ok(-2));
+    /// assert_eq!(Bb::ulps(1.0f64, f64::NAN), Err(BitcastError::Nan));
+    /// ```
+    pub fn ulps<F: Ulps>(a: F, b: F) -> Result<i64, BitcastError> {
+        a.ulps(b)
+    }
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`isnan` requires an `f32` or `f64` input, not `{Self}`",
+    label = "not a float",
+    note = "to classify integer bits, reinterpret them first with `f32::from_bits` or `f64::from_bits`"
+)]
+pub trait IsNanInput: Copy {
+    fn is_nan(self) -> bool;
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`isfinite` requires an `f32` or `f64` input, not `{Self}`",
+    label = "not a float",
+    note = "to classify integer bits, reinterpret them first with `f32::from_bits` or `f64::from_bits`"
+)]
+pub trait IsFiniteInput: Copy {
+    fn is_finite(self) -> bool;
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`issubnormal` requires an `f32` or `f64` input, not `{Self}`",
+    label = "not a float",
+    note = "to classify integer bits, reinterpret them first with `f32::from_bits` or `f64::from_bits`"
+)]
+pub trait IsSubnormalInput: Copy {
+    fn is_subnormal(self) -> bool;
+}
+
+#[doc(hidden)]
+macro_rules! impl_float_class {
+    ($($F:ty: $U:ty, $mantissa:literal);*) => {
+        $(
+            impl IsNanInput for $F {
+                fn is_nan(self) -> bool {
+                    const EXP: $U = <$U>::MAX >> 1 >> $mantissa << $mantissa;
+                    self.to_bits() << 1 >> 1 > EXP
+                }
+            }
+
+            impl IsFiniteInput for $F {
+                fn is_finite(self) -> bool {
+                    const EXP: $U = <$U>::MAX >> 1 >> $mantissa << $mantissa;
+                    self.to_bits() & EXP != EXP
+                }
+            }
+
+            impl IsSubnormalInput for $F {
+                fn is_subnormal(self) -> bool {
+                    const EXP: $U = <$U>::MAX >> 1 >> $mantissa << $mantissa;
+                    let bits = self.to_bits();
+                    bits & EXP == 0 && bits << 1 != 0
+                }
+            }
+        )*
+    };
+}
+
+impl_float_class!(f32: u32, 23; f64: u64, 52);
+
+impl<F: IsNanInput> AppliedTo<F> for IsNan {
+    type Output = bool;
+
+    fn apply(src: F) -> bool {
+        IsNanInput::is_nan(src)
+    }
+}
+
+impl<F: IsFiniteInput> AppliedTo<F> for IsFinite {
+    type Output = bool;
+
+    fn apply(src: F) -> bool {
+        IsFiniteInput::is_finite(src)
+    }
+}
+
+impl<F: IsSubnormalInput> AppliedTo<F> for IsSubnormal {
+    type Output = bool;
+
+    fn apply(src: F) -> bool {
+        IsSubnormalInput::is_subnormal(src)
+    }
+}
+
+/// Bits of the half precision float nearest to `x`, or the nearest one towards zero without `NEAREST`.
+fn f32_to_f16<const NEAREST: bool>(x: f32) -> u16 {
+    let bits = x.to_bits();
+    let sign = (bits >> 16) as u16 & 0x8000;
+    let exp = (bits >> 23 & 0xff) as i32;
+    let man = bits & 0x7f_ffff;
+    if exp == 0xff {
+        // keep NaNs quiet and nonzero
+        return sign | 0x7c00 | if man != 0 { 0x200 | (man >> 13) as u16 } else { 0 };
+    }
+    if exp == 0 {
+        // f32 subnormals are far below the smallest half subnormal
+        return sign;
+    }
+    let e = exp - 127 + 15;
+    let shift = if e >= 1 { 13 } else { 14 - e } as u32;
+    if shift > 24 {
+        return sign;
+    }
+    let m = man | 0x80_0000;
+    let mut q = m >> shift;
+    let r = m & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if NEAREST && (r > half || r == half && q & 1 == 1) {
+        q += 1;
+    }
+    // a rounding carry out of the significand increments the exponent
+    let h = if e >= 1 { ((e as u32 - 1) << 10) + q } else { q };
+    match h >= 0x7c00 {
+        true if NEAREST => sign | 0x7c00,
+        true => sign | 0x7bff,
+        false => sign | h as u16,
+    }
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`f16_rne` and `f16_rtz` require an `f32` input, not `{Self}`",
+    label = "not an `f32`",
+    note = "convert the value to `f32` first"
+)]
+pub trait F16Input: Copy {
+    fn to_f16<const NEAREST: bool>(self) -> u16;
+}
+
+impl F16Input for f32 {
+    fn to_f16<const NEAREST: bool>(self) -> u16 {
+        f32_to_f16::<NEAREST>(self)
+    }
+}
+
+impl<F: F16Input> AppliedTo<F> for F16Rne {
+    type Output = u16;
+
+    fn apply(src: F) -> u16 {
+        src.to_f16::<true>()
+    }
+}
+
+impl<F: F16Input> AppliedTo<F> for F16Rtz {
+    type Output = u16;
+
+    fn apply(src: F) -> u16 {
+        src.to_f16::<false>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f2i() {
+        assert_eq!(Bb::f2i::<u8, NanZero>(f32::NAN), 0);
+        assert_eq!(Bb::f2i::<i64, NanZero>(-f64::NAN), 0);
+        assert_eq!(Bb::f2i::<u8, NanError>(f32::NAN), Err(BitcastError::Nan));
+        assert_eq!(Bb::f2i::<i128, NanError>(f64::NAN), Err(BitcastError::Nan));
+        for (x, sat) in [(0.0, 0), (-0.0, 0), (1.9, 1), (-1.9, -1), (127.9, 127), (128.0, 127), (-129.0, -128)] {
+            assert_eq!(Bb::f2i::<i8, NanZero>(x), sat);
+            assert_eq!(Bb::f2i::<i8, NanError>(x), Ok(sat));
+            assert_eq!(Bb::f2i::<i8, NanZero>(x as f32), sat);
+        }
+        assert_eq!(Bb::f2i::<u16, NanZero>(f32::INFINITY), u16::MAX);
+        assert_eq!(Bb::f2i::<u16, NanError>(f32::NEG_INFINITY), Ok(0));
+        assert_eq!(Bb::f2i::<u128, NanZero>(f64::MAX), u128::MAX);
+        assert_eq!(Bb::f2i::<i32, NanZero>(-1e10f64), i32::MIN);
+    }
+
+    #[test]
+    fn f16() {
+        for (x, h) in [
+            (0.0f32, 0x0000u16), (-0.0, 0x8000), (1.0, 0x3c00), (-2.0, 0xc000), (0.5, 0x3800),
+            (65504.0, 0x7bff), (f32::INFINITY, 0x7c00), (f32::NEG_INFINITY, 0xfc00),
+            (6.1035156e-5, 0x0400), (5.9604645e-8, 0x0001), (1e-10, 0), (f32::from_bits(1), 0),
+        ] {
+            assert_eq!(x * Bb.f16_rne, h, "{x:?}");
+            assert_eq!(x * Bb.f16_rtz, h, "{x:?}");
+        }
+        // 1.75 half ulps above 1 rounds up to nearest, but down towards zero
+        let x = 1.0 + 7.0 * (2.0f32).powi(-12);
+        assert_eq!(x * Bb.f16_rne, 0x3c02);
+        assert_eq!(x * Bb.f16_rtz, 0x3c01);
+        assert_eq!(-x * Bb.f16_rne, 0xbc02);
+        assert_eq!(-x * Bb.f16_rtz, 0xbc01);
+        // ties go to even
+        assert_eq!((1.0 + (2.0f32).powi(-11)) * Bb.f16_rne, 0x3c00);
+        // overflow
+        assert_eq!(65519.0f32 * Bb.f16_rne, 0x7bff);
+        assert_eq!(65520.0f32 * Bb.f16_rne, 0x7c00);
+        assert_eq!(65520.0f32 * Bb.f16_rtz, 0x7bff);
+        assert_eq!(1e10f32 * Bb.f16_rne, 0x7c00);
+        assert_eq!(-1e10f32 * Bb.f16_rtz, 0xfbff);
+        // subnormals round like normals, possibly up to the smallest normal
+        assert_eq!((3.0 * 5.9604645e-8f32) * Bb.f16_rne, 0x0003);
+        assert_eq!((2.5 * 5.9604645e-8f32) * Bb.f16_rne, 0x0002);
+        assert_eq!((2.5 * 5.9604645e-8f32) * Bb.f16_rtz, 0x0002);
+        assert_eq!((2.75 * 5.9604645e-8f32) * Bb.f16_rne, 0x0003);
+        assert_eq!(6.1035e-5f32 * Bb.f16_rne, 0x0400);
+        assert_eq!(6.1035e-5f32 * Bb.f16_rtz, 0x03ff);
+        assert_eq!((0.5 * 5.9604645e-8f32) * Bb.f16_rne, 0);
+        assert_eq!((0.51 * 5.9604645e-8f32) * Bb.f16_rne, 1);
+        // NaN stays NaN
+        assert_eq!((f32::NAN * Bb.f16_rne) & 0x7c00, 0x7c00);
+        assert_ne!((f32::NAN * Bb.f16_rne) & 0x3ff, 0);
+        assert_ne!((f32::from_bits(0x7f80_0001) * Bb.f16_rtz) & 0x3ff, 0);
+    }
+
+    #[test]
+    fn ulps() {
+        assert_eq!(Bb::ulps(1.0f32, 1.0), Ok(0));
+        assert_eq!(Bb::ulps(0.0f32, -0.0), Ok(0));
+        assert_eq!(Bb::ulps(1.0f32, f32::from_bits(1.0f32.to_bits() + 1)), Ok(1));
+        assert_eq!(Bb::ulps(f32::from_bits(1.0f32.to_bits() + 1), 1.0), Ok(-1));
+        assert_eq!(Bb::ulps(-1.0f32, f32::from_bits((-1.0f32).to_bits() - 1)), Ok(1));
+        assert_eq!(Bb::ulps(0.0f32, f32::from_bits(1)), Ok(1));
+        assert_eq!(Bb::ulps(-f32::from_bits(3), f32::from_bits(2)), Ok(5));
+        assert_eq!(Bb::ulps(f32::MAX, f32::INFINITY), Ok(1));
+        assert_eq!(Bb::ulps(f32::NEG_INFINITY, f32::INFINITY), Ok(2 * 0x7f80_0000));
+        assert_eq!(Bb::ulps(f32::NAN, 1.0), Err(BitcastError::Nan));
+        assert_eq!(Bb::ulps(1.0, -f32::NAN), Err(BitcastError::Nan));
+        assert_eq!(Bb::ulps(1.0f64, f64::from_bits(1.0f64.to_bits() + 1)), Ok(1));
+        assert_eq!(Bb::ulps(-f64::from_bits(1), f64::from_bits(1)), Ok(2));
+        assert_eq!(Bb::ulps(1.0f64, 2.0), Ok(1 << 52));
+        assert_eq!(Bb::ulps(0.0f64, f64::INFINITY), Ok(0x7ff0_0000_0000_0000));
+        assert_eq!(Bb::ulps(f64::NEG_INFINITY, f64::INFINITY), Err(BitcastError::Overflow { target: "i64" }));
+        assert_eq!(Bb::ulps(f64::INFINITY, f64::NEG_INFINITY), Err(BitcastError::Underflow { target: "i64" }));
+        assert_eq!(Bb::ulps(f64::NAN, f64::NAN), Err(BitcastError::Nan));
+    }
+
+    #[test]
+    fn float_class() {
+        let f32s = [
+            0.0, -0.0, 1.0, -1.5, f32::MIN_POSITIVE, f32::MAX, f32::MIN, f32::INFINITY, f32::NEG_INFINITY,
+            f32::NAN, -f32::NAN, f32::from_bits(1), f32::from_bits(0x807f_ffff), f32::from_bits(0x7f80_0001),
+            f32::from_bits(0xffc0_0000),
+        ];
+        for x in f32s {
+            assert_eq!(x * Bb.isnan, x.is_nan(), "{x:?}");
+            assert_eq!(x * Bb.isfinite, x.is_finite(), "{x:?}");
+            assert_eq!(x * Bb.issubnormal, x.is_subnormal(), "{x:?}");
+        }
+        let f64s = [
+            0.0, -0.0, 1.0, -1.5, f64::MIN_POSITIVE, f64::MAX, f64::MIN, f64::INFINITY, f64::NEG_INFINITY,
+            f64::NAN, -f64::NAN, f64::from_bits(1), f64::from_bits(0x800f_ffff_ffff_ffff),
+            f64::from_bits(0x7ff0_0000_0000_0001), f64::MIN_POSITIVE / 2.0,
+        ];
+        for x in f64s {
+            assert_eq!(x * Bb.isnan, x.is_nan(), "{x:?}");
+            assert_eq!(x * Bb.isfinite, x.is_finite(), "{x:?}");
+            assert_eq!(x * Bb.issubnormal, x.is_subnormal(), "{x:?}");
+        }
+        assert!(f32::NAN * Bb.isnan);
+        assert!(!(f64::INFINITY * Bb.isfinite));
+        assert!(f32::from_bits(1) * Bb.issubnormal);
+        assert!(!(0.0f64 * Bb.issubnormal));
+    }
+}
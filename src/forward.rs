@@ -0,0 +1,99 @@
+//! Chains applied to containers of integers, forwarded to the contained values.
+
+use core::num::Saturating;
+use core::ops::Mul;
+
+use crate::RA;
+use crate::RL;
+
+impl<T: Mul<RL<Op, U>>, Op, U> Mul<RL<Op, U>> for Option<T> {
+    type Output = Option<T::Output>;
+
+    fn mul(self, rhs: RL<Op, U>) -> Self::Output {
+        self.map(|x| x * rhs)
+    }
+}
+
+impl<T: Mul<RA<Op, U>>, Op, U> Mul<RA<Op, U>> for Option<T> {
+    type Output = Option<T::Output>;
+
+    fn mul(self, rhs: RA<Op, U>) -> Self::Output {
+        self.map(|x| x * rhs)
+    }
+}
+
+impl<T: Mul<RL<Op, U>>, E, Op, U> Mul<RL<Op, U>> for Result<T, E> {
+    type Output = Result<T::Output, E>;
+
+    fn mul(self, rhs: RL<Op, U>) -> Self::Output {
+        self.map(|x| x * rhs)
+    }
+}
+
+impl<T: Mul<RA<Op, U>>, E, Op, U> Mul<RA<Op, U>> for Result<T, E> {
+    type Output = Result<T::Output, E>;
+
+    fn mul(self, rhs: RA<Op, U>) -> Self::Output {
+        self.map(|x| x * rhs)
+    }
+}
+
+/// The chain keeps its own semantics inside the wrapper: `Saturating(300u16) * Bb.t8` wraps to `Saturating(44u8)`.
+/// Use [sat](crate::CastSigned::sat) for a saturating conversion.
+impl<T: Mul<RL<Op, U>>, Op, U> Mul<RL<Op, U>> for Saturating<T> {
+    type Output = Saturating<T::Output>;
+
+    fn mul(self, rhs: RL<Op, U>) -> Self::Output {
+        Saturating(self.0 * rhs)
+    }
+}
+
+impl<T: Mul<RA<Op, U>>, Op, U> Mul<RA<Op, U>> for Saturating<T> {
+    type Output = Saturating<T::Output>;
+
+    fn mul(self, rhs: RA<Op, U>) -> Self::Output {
+        Saturating(self.0 * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    #[test]
+    fn option() {
+        assert_eq!(Some(5u8) * Bb.e32, Some(5u32));
+        assert_eq!(None::<u8> * Bb.e32, None);
+        assert_eq!(Some(0x1ffu16) * Bb.t8, Some(0xffu8));
+        assert_eq!(None::<u16> * Bb.t8, None);
+        assert_eq!(Some(-1i8) * Bb.u.e16, Some(0xffu16));
+        assert_eq!(Some(0x30u8) * Bb.shls(3), Some(0xff));
+        assert_eq!(300u16 * Bb.ct8 * Bb.e32, None);
+        assert_eq!(200u16 * Bb.ct8 * Bb.s.e32, Some(-56));
+    }
+
+    #[test]
+    fn result() {
+        use crate::BitcastError;
+
+        assert_eq!(Ok::<u8, ()>(5) * Bb.e32, Ok(5u32));
+        assert_eq!(Err::<u8, &str>("bad") * Bb.e32, Err("bad"));
+        assert_eq!(Ok::<u32, ()>(0x1234_5678) * Bb.t16.swapbytes16, Ok(0x7856u16));
+        assert_eq!(Err::<u32, ()>(()) * Bb.t16.swapbytes16, Err(()));
+        assert_eq!(200u16 * Bb.try_to::<u8>() * Bb.e64.s, Ok(200i64));
+        assert_eq!(-1i16 * Bb.try_to::<u8>() * Bb.e64.s, Err(BitcastError::Underflow { target: "u8" }));
+    }
+
+    #[test]
+    fn saturating() {
+        use core::num::Saturating;
+
+        assert_eq!(Saturating(200u8) * Bb.e16, Saturating(200u16));
+        assert_eq!(Saturating(-1i8) * Bb.e64, Saturating(-1i64));
+        // narrowing wraps like the bare chain does
+        assert_eq!(Saturating(300u16) * Bb.t8, Saturating(44u8));
+        assert_eq!(Saturating(300u16) * Bb.sat::<u8>(), Saturating(255u8));
+        assert_eq!(Saturating(0x30u8) * Bb.shls(3), Saturating(0xff));
+        assert_eq!(Saturating(250u8) * Bb.e16 + Saturating(u16::MAX), Saturating(u16::MAX));
+    }
+}
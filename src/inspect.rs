@@ -0,0 +1,55 @@
+use core::marker::PhantomData;
+
+use crate::AppliedWith;
+use crate::CastSigned;
+use crate::RA;
+
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Also<F>(F);
+
+impl<U> CastSigned<U> {
+    /// Calls `f` with the value produced by the chain so far, and passes the value on unchanged.
+    ///
+    /// This evaluates the chain up to this point, so it's useful for looking at intermediates while debugging.
+    /// Like other methods with runtime arguments it ends the chain, which continues with another `* Bb`.
+    /// The closure parameter usually needs a type annotation.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let mut seen = 0;
+    /// let x = 0x1234u16 * Bb.t8.also(|v: &u8| seen = *v) * Bb.s.e32;
+    /// assert_eq!((seen, x), (0x34, 0x34));
+    /// ```
+    pub fn also<F>(&self, f: F) -> RA<Also<F>, U> {
+        RA(Also(f), PhantomData)
+    }
+}
+
+impl<T, F: FnOnce(&T)> AppliedWith<T> for Also<F> {
+    type Output = T;
+
+    fn apply_with(self, src: T) -> T {
+        (self.0)(&src);
+        src
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    #[test]
+    fn also() {
+        let mut seen = [0i32; 2];
+        let x = -1i8 * Bb.e16.u.also(|v: &u16| seen[0] = *v as i32) * Bb.e32.s.also(|v: &i32| seen[1] = *v);
+        assert_eq!(x, 0xffff);
+        assert_eq!(seen, [0xffff, 0xffff]);
+        let mut seen = None;
+        assert_eq!(300u16 * Bb.ct8.also(|v: &Option<u8>| seen = Some(*v)), None);
+        assert_eq!(seen, Some(None));
+        let mut calls = 0;
+        let _ = 5u32 * Bb.also(|_: &u32| calls += 1);
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,59 @@
+use alloc::vec::Vec;
+
+use crate::convert::TryTo;
+use crate::AppliedTo;
+use crate::BitcastError;
+use crate::CastFrom;
+use crate::SaturatingFrom;
+
+/// Collects iterators of integers into vectors of another integer type. Requires the `alloc` feature.
+///
+/// ```
+/// use yabe::CastIterExt;
+///
+/// let src = [0x1ffu16, 0x7f, 0x80];
+/// assert_eq!(src.iter().copied().cast_collect::<u8>(), [0xff, 0x7f, 0x80]);
+/// assert_eq!(src.iter().copied().sat_collect::<i8>(), [0x7f, 0x7f, 0x7f]);
+/// ```
+pub trait CastIterExt: Iterator + Sized {
+    /// Converts every item like `as` does, truncating or extending as needed.
+    fn cast_collect<T: CastFrom<Self::Item>>(self) -> Vec<T> {
+        self.map(T::cast_from).collect()
+    }
+
+    /// Converts every item, clamping it into the range of `T`.
+    fn sat_collect<T: SaturatingFrom<Self::Item>>(self) -> Vec<T> {
+        self.map(T::saturating_from).collect()
+    }
+
+    /// Converts every item, stopping at the first one which isn't representable in `T`.
+    fn try_cast_collect<T>(self) -> Result<Vec<T>, BitcastError>
+    where
+        TryTo<T>: AppliedTo<Self::Item, Output = Result<T, BitcastError>>,
+    {
+        self.map(TryTo::<T>::apply).collect()
+    }
+}
+
+impl<I: Iterator> CastIterExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn cast_collect() {
+        let src = [-1i8, 0, 0x7f, -0x80];
+        assert_eq!(src.into_iter().cast_collect::<i32>(), vec![-1, 0, 0x7f, -0x80]);
+        assert_eq!(src.into_iter().cast_collect::<u16>(), vec![0xffff, 0, 0x7f, 0xff80]);
+        let wide = [0x1_0000u32, 0xffff, 0x1234_5678];
+        assert_eq!(wide.into_iter().cast_collect::<u16>(), vec![0, 0xffff, 0x5678]);
+        assert_eq!(wide.into_iter().sat_collect::<u16>(), vec![0xffff, 0xffff, 0xffff]);
+        assert_eq!(src.into_iter().sat_collect::<u8>(), vec![0, 0, 0x7f, 0]);
+        assert_eq!([1u64, 2].into_iter().try_cast_collect::<u8>(), Ok(vec![1, 2]));
+        assert_eq!(wide.into_iter().try_cast_collect::<u16>(), Err(BitcastError::Overflow { target: "u16" }));
+        assert_eq!(src.into_iter().try_cast_collect::<u8>(), Err(BitcastError::Underflow { target: "u8" }));
+        assert_eq!(core::iter::empty::<u8>().cast_collect::<u128>(), vec![]);
+    }
+}
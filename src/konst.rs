@@ -0,0 +1,92 @@
+use crate::Bb;
+
+#[doc(hidden)]
+macro_rules! impl_const_steps {
+    ($($name:ident = $field:ident: $S:ty => $T:ty),*) => {
+        impl Bb {
+            $(
+                #[doc = concat!("`x * Bb.", stringify!($field), "` for `x: ", stringify!($S), "` as a `const fn`.")]
+                pub const fn $name(x: $S) -> $T {
+                    x as $T
+                }
+            )*
+        }
+    };
+}
+
+impl_const_steps! {
+    s_u8 = s: u8 => i8,
+    s_u16 = s: u16 => i16,
+    s_u32 = s: u32 => i32,
+    s_u64 = s: u64 => i64,
+    s_u128 = s: u128 => i128,
+    u_i8 = u: i8 => u8,
+    u_i16 = u: i16 => u16,
+    u_i32 = u: i32 => u32,
+    u_i64 = u: i64 => u64,
+    u_i128 = u: i128 => u128,
+    t8_u16 = t8: u16 => u8,
+    t8_u32 = t8: u32 => u8,
+    t16_u32 = t16: u32 => u16,
+    t8_u64 = t8: u64 => u8,
+    t16_u64 = t16: u64 => u16,
+    t32_u64 = t32: u64 => u32,
+    t8_u128 = t8: u128 => u8,
+    t16_u128 = t16: u128 => u16,
+    t32_u128 = t32: u128 => u32,
+    t64_u128 = t64: u128 => u64,
+    t8_i16 = t8: i16 => i8,
+    t8_i32 = t8: i32 => i8,
+    t16_i32 = t16: i32 => i16,
+    t8_i64 = t8: i64 => i8,
+    t16_i64 = t16: i64 => i16,
+    t32_i64 = t32: i64 => i32,
+    t8_i128 = t8: i128 => i8,
+    t16_i128 = t16: i128 => i16,
+    t32_i128 = t32: i128 => i32,
+    t64_i128 = t64: i128 => i64,
+    e16_u8 = e16: u8 => u16,
+    e32_u8 = e32: u8 => u32,
+    e64_u8 = e64: u8 => u64,
+    e128_u8 = e128: u8 => u128,
+    e32_u16 = e32: u16 => u32,
+    e64_u16 = e64: u16 => u64,
+    e128_u16 = e128: u16 => u128,
+    e64_u32 = e64: u32 => u64,
+    e128_u32 = e128: u32 => u128,
+    e128_u64 = e128: u64 => u128,
+    e16_i8 = e16: i8 => i16,
+    e32_i8 = e32: i8 => i32,
+    e64_i8 = e64: i8 => i64,
+    e128_i8 = e128: i8 => i128,
+    e32_i16 = e32: i16 => i32,
+    e64_i16 = e64: i16 => i64,
+    e128_i16 = e128: i16 => i128,
+    e64_i32 = e64: i32 => i64,
+    e128_i32 = e128: i32 => i128,
+    e128_i64 = e128: i64 => i128
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    static SIGNED: i8 = Bb::s_u8(0xff);
+    static UNSIGNED: u32 = Bb::u_i32(-1);
+    static NARROW: [u8; 2] = [Bb::t8_u32(0x1234_5678), Bb::t8_u16(0x1ff)];
+    static WIDE: i64 = Bb::e64_i8(-2);
+    static ZEXT: u128 = Bb::e128_u64(u64::MAX);
+    const CHAINED: i16 = Bb::s_u16(Bb::e16_u8(Bb::t8_u32(0x80)));
+
+    #[test]
+    fn const_steps() {
+        assert_eq!(SIGNED, 0xffu8 * Bb.s);
+        assert_eq!(UNSIGNED, -1i32 * Bb.u);
+        assert_eq!(NARROW, [0x1234_5678u32 * Bb.t8, 0x1ffu16 * Bb.t8]);
+        assert_eq!(WIDE, -2i8 * Bb.e64);
+        assert_eq!(ZEXT, u64::MAX * Bb.e128);
+        assert_eq!(CHAINED, 0x80u32 * Bb.t8.e16.s);
+        assert_eq!(Bb::t32_i128(-1), -1i128 * Bb.t32);
+        assert_eq!(Bb::e32_i16(i16::MIN), i16::MIN * Bb.e32);
+    }
+}
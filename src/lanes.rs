@@ -0,0 +1,428 @@
+use crate::AppliedTo;
+use crate::Bb;
+use crate::HighByte;
+use crate::LowByte;
+use crate::NibbleParity;
+use crate::PopcountPerByte;
+use crate::Reflect8;
+use crate::ReverseNibbles;
+use crate::Swap16Lanes;
+use crate::SwapBytes16;
+use crate::SwapNibbles;
+use crate::Swap32Lanes;
+
+/// Signed impls reinterpret the value as unsigned and back.
+#[doc(hidden)]
+macro_rules! via_unsigned {
+    ($Op:ty: $($S:ty > $U:ty),*) => {
+        $(
+            impl AppliedTo<$S> for $Op {
+                type Output = $S;
+
+                fn apply(src: $S) -> $S {
+                    <$Op as AppliedTo<$U>>::apply(src as $U) as $S
+                }
+            }
+        )*
+    };
+}
+
+#[doc(hidden)]
+macro_rules! impl_swapbytes16 {
+    ($($U:ty)*) => {
+        $(
+            impl AppliedTo<$U> for SwapBytes16 {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    const M: $U = <$U>::MAX / 0xffff * 0xff;
+                    ((src >> 8) & M) | ((src & M) << 8)
+                }
+            }
+        )*
+    };
+}
+
+impl_swapbytes16!(u16 u32 u64 u128);
+via_unsigned!(SwapBytes16: i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+
+#[doc(hidden)]
+macro_rules! impl_swap16 {
+    ($($U:ty)*) => {
+        $(
+            impl AppliedTo<$U> for Swap16Lanes {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    <SwapBytes16 as AppliedTo<$U>>::apply(src.swap_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_swap16!(u32 u64 u128);
+via_unsigned!(Swap16Lanes: i32 > u32, i64 > u64, i128 > u128);
+
+#[doc(hidden)]
+macro_rules! impl_swap32 {
+    ($($U:ty)*) => {
+        $(
+            impl AppliedTo<$U> for Swap32Lanes {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    const M: $U = <$U>::MAX / 0xffff_ffff * 0xffff;
+                    let x = <Swap16Lanes as AppliedTo<$U>>::apply(src);
+                    ((x >> 16) & M) | ((x & M) << 16)
+                }
+            }
+        )*
+    };
+}
+
+impl_swap32!(u64 u128);
+via_unsigned!(Swap32Lanes: i64 > u64, i128 > u128);
+
+#[doc(hidden)]
+macro_rules! impl_swapnib {
+    ($($U:ty)*) => {
+        $(
+            impl AppliedTo<$U> for SwapNibbles {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    const M: $U = <$U>::MAX / 0xff * 0x0f;
+                    ((src & M) << 4) | ((src >> 4) & M)
+                }
+            }
+        )*
+    };
+}
+
+impl_swapnib!(u8 u16 u32 u64 u128);
+via_unsigned!(SwapNibbles: i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+
+#[doc(hidden)]
+macro_rules! impl_revnib {
+    ($($U:ty)*) => {
+        $(
+            impl AppliedTo<$U> for ReverseNibbles {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    <SwapNibbles as AppliedTo<$U>>::apply(src.swap_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_revnib!(u8 u16 u32 u64 u128);
+via_unsigned!(ReverseNibbles: i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+
+#[doc(hidden)]
+macro_rules! impl_reflect8 {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for Reflect8 {
+                type Output = $T;
+
+                fn apply(src: $T) -> $T {
+                    // full bit reversal also reverses the byte order, so restore it
+                    src.reverse_bits().swap_bytes()
+                }
+            }
+        )*
+    };
+}
+
+impl_reflect8!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+#[doc(hidden)]
+macro_rules! impl_ones8 {
+    ($($U:ty)*) => {
+        $(
+            impl AppliedTo<$U> for PopcountPerByte {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    const M1: $U = <$U>::MAX / 3;
+                    const M2: $U = <$U>::MAX / 5;
+                    const M4: $U = <$U>::MAX / 17;
+                    let x = src - ((src >> 1) & M1);
+                    let x = (x & M2) + ((x >> 2) & M2);
+                    (x + (x >> 4)) & M4
+                }
+            }
+        )*
+    };
+}
+
+impl_ones8!(u8 u16 u32 u64 u128);
+via_unsigned!(PopcountPerByte: i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+
+#[doc(hidden)]
+macro_rules! impl_nibparity {
+    ($($U:ty)*) => {
+        $(
+            impl AppliedTo<$U> for NibbleParity {
+                type Output = $U;
+
+                fn apply(src: $U) -> $U {
+                    // both shifts only move bits within a nibble into its low bit
+                    let x = src ^ (src >> 2);
+                    let x = x ^ (x >> 1);
+                    x & <$U>::MAX / 0xf
+                }
+            }
+        )*
+    };
+}
+
+impl_nibparity!(u8 u16 u32 u64 u128);
+via_unsigned!(NibbleParity: i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+
+#[doc(hidden)]
+macro_rules! impl_end_bytes {
+    ($($T:ty)*) => {
+        $(
+            impl AppliedTo<$T> for LowByte {
+                type Output = u8;
+
+                fn apply(src: $T) -> u8 {
+                    src as u8
+                }
+            }
+
+            impl AppliedTo<$T> for HighByte {
+                type Output = u8;
+
+                fn apply(src: $T) -> u8 {
+                    (src >> (<$T>::BITS - 8)) as u8
+                }
+            }
+        )*
+    };
+}
+
+impl_end_bytes!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+
+impl Bb {
+    /// Packs the four `i16` lanes of `word` into four `i8` lanes, saturating each, like SSE `packsswb`.
+    /// Lane `i` of the result comes from lane `i` of `word`, counting from the least significant.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::packs_i16_i8(0x0005_ff00_fff0_0100), 0x05_80_f0_7f);
+    /// ```
+    pub fn packs_i16_i8(word: u64) -> u32 {
+        (0..4).fold(0, |res, i| res | (((word >> (16 * i)) as i16).clamp(-0x80, 0x7f) as u8 as u32) << (8 * i))
+    }
+
+    /// Packs the four `i16` lanes of `word` into four `u8` lanes, saturating each, like SSE `packuswb`.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::packus_i16_u8(0x0005_ff00_fff0_0100), 0x05_00_00_ff);
+    /// ```
+    pub fn packus_i16_u8(word: u64) -> u32 {
+        (0..4).fold(0, |res, i| res | (((word >> (16 * i)) as i16).clamp(0, 0xff) as u32) << (8 * i))
+    }
+
+    /// Sign extends the low four `i8` lanes of `word` into four `i16` lanes, like SSE `punpcklbw`
+    /// followed by a sign extension. The inverse of [packs_i16_i8](Bb::packs_i16_i8) for lanes that fit.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::unpack_lo_i8_i16(0x05_80_f0_7f), 0x0005_ff80_fff0_007f);
+    /// ```
+    pub fn unpack_lo_i8_i16(word: u64) -> u64 {
+        (0..4).fold(0, |res, i| res | ((word >> (8 * i)) as i8 as u16 as u64) << (16 * i))
+    }
+
+    /// Sign extends the high four `i8` lanes of `word` into four `i16` lanes, like SSE `punpckhbw`
+    /// followed by a sign extension.
+    pub fn unpack_hi_i8_i16(word: u64) -> u64 {
+        Bb::unpack_lo_i8_i16(word >> 32)
+    }
+
+    /// Zero extends the low four `u8` lanes of `word` into four `u16` lanes, like SSE `punpcklbw` with zero.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(Bb::unpack_lo_u8_u16(0x05_80_f0_7f), 0x0005_0080_00f0_007f);
+    /// ```
+    pub fn unpack_lo_u8_u16(word: u64) -> u64 {
+        (0..4).fold(0, |res, i| res | ((word >> (8 * i)) as u8 as u64) << (16 * i))
+    }
+
+    /// Zero extends the high four `u8` lanes of `word` into four `u16` lanes, like SSE `punpckhbw` with zero.
+    pub fn unpack_hi_u8_u16(word: u64) -> u64 {
+        Bb::unpack_lo_u8_u16(word >> 32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    #[test]
+    fn packs() {
+        let lanes = |l: [i16; 4]| l.iter().rev().fold(0u64, |w, &x| w << 16 | x as u16 as u64);
+        let bytes = |b: [u8; 4]| u32::from_le_bytes(b);
+        let word = lanes([300, -300, 5, -5]);
+        assert_eq!(Bb::packs_i16_i8(word), bytes([0x7f, 0x80, 5, 0xfb]));
+        assert_eq!(Bb::packus_i16_u8(word), bytes([0xff, 0, 5, 0]));
+        let word = lanes([127, -128, 128, -129]);
+        assert_eq!(Bb::packs_i16_i8(word), bytes([0x7f, 0x80, 0x7f, 0x80]));
+        let word = lanes([255, 256, 0, i16::MIN]);
+        assert_eq!(Bb::packus_i16_u8(word), bytes([0xff, 0xff, 0, 0]));
+        assert_eq!(Bb::packs_i16_i8(lanes([i16::MAX, i16::MIN, 0, -1])), bytes([0x7f, 0x80, 0, 0xff]));
+        assert_eq!(Bb::packs_i16_i8(0), 0);
+        assert_eq!(Bb::packus_i16_u8(u64::MAX), 0);
+    }
+
+    #[test]
+    fn unpack() {
+        let bytes = |b: [u8; 8]| u64::from_le_bytes(b);
+        let lanes = |l: [u16; 4]| l.iter().rev().fold(0u64, |w, &x| w << 16 | x as u64);
+        let word = bytes([0x7f, 0x80, 0xff, 0, 1, 0xfe, 0x81, 0x40]);
+        assert_eq!(Bb::unpack_lo_i8_i16(word), lanes([0x7f, 0xff80, 0xffff, 0]));
+        assert_eq!(Bb::unpack_hi_i8_i16(word), lanes([1, 0xfffe, 0xff81, 0x40]));
+        assert_eq!(Bb::unpack_lo_u8_u16(word), lanes([0x7f, 0x80, 0xff, 0]));
+        assert_eq!(Bb::unpack_hi_u8_u16(word), lanes([1, 0xfe, 0x81, 0x40]));
+        assert_eq!(Bb::unpack_lo_i8_i16(u32::MAX.into()), u64::MAX);
+        assert_eq!(Bb::unpack_hi_i8_i16(u32::MAX.into()), 0);
+        for x in [0u32, 0x7f80_01ff, 0xdead_beef, u32::MAX] {
+            assert_eq!(Bb::packs_i16_i8(Bb::unpack_lo_i8_i16(x.into())), x);
+            assert_eq!(Bb::packus_i16_u8(Bb::unpack_lo_u8_u16(x.into())), x);
+        }
+    }
+
+    #[test]
+    fn nibparity() {
+        assert_eq!(0x0137u16 * Bb.nibparity, 0x0101);
+        assert_eq!(0xf8e6u16 * Bb.nibparity, 0x0110);
+        assert_eq!(0u32 * Bb.nibparity, 0);
+        assert_eq!(u64::MAX * Bb.nibparity, 0);
+        assert_eq!(0x1248_1248u32 * Bb.nibparity, 0x1111_1111);
+        assert_eq!(-1i8 * Bb.nibparity, 0);
+        assert_eq!(i8::MIN * Bb.nibparity, 0x10);
+        for x in (0..=u16::MAX).step_by(97) {
+            let p = x * Bb.nibparity;
+            for i in 0..4 {
+                assert_eq!(p >> (4 * i) & 0xf, (x >> (4 * i) & 0xf).count_ones() as u16 & 1);
+            }
+        }
+        let x = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        assert_eq!(x * Bb.nibparity, 0x0110_1001_1001_0110_0110_1001_1001_0110);
+    }
+
+    #[test]
+    fn end_bytes() {
+        assert_eq!(0x1234u16 * Bb.lowb, 0x34u8);
+        assert_eq!(0x1234u16 * Bb.highb, 0x12u8);
+        assert_eq!(0x1234_5678u32 * Bb.lowb, 0x78u8);
+        assert_eq!(0x1234_5678u32 * Bb.highb, 0x12u8);
+        assert_eq!(0x0102_0304_0506_0708u64 * Bb.lowb, 0x08);
+        assert_eq!(0x0102_0304_0506_0708u64 * Bb.highb, 0x01);
+        assert_eq!(0xabu8 * Bb.lowb, 0xab);
+        assert_eq!(0xabu8 * Bb.highb, 0xab);
+        assert_eq!(-2i32 * Bb.lowb, 0xfe);
+        assert_eq!(i32::MIN * Bb.highb, 0x80);
+        assert_eq!((u128::MAX >> 1) * Bb.highb, 0x7f);
+        for x in [0u32, 1, 0xdead_beef, u32::MAX] {
+            assert_eq!(x * Bb.lowb, x * Bb.t8);
+            assert_eq!(x * Bb.highb, x.to_be_bytes()[0]);
+        }
+    }
+
+    #[test]
+    fn swapbytes16() {
+        assert_eq!(0x1234u16 * Bb.swapbytes16, 0x3412);
+        assert_eq!(0x1234_5678u32 * Bb.swapbytes16, 0x3412_7856);
+        assert_eq!(0x1234_5678u32.swap_bytes(), 0x7856_3412);
+        assert_eq!(0x0102_0304_0506_0708u64 * Bb.swapbytes16, 0x0201_0403_0605_0807);
+        assert_eq!(
+            0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128 * Bb.swapbytes16,
+            0x0201_0403_0605_0807_0a09_0c0b_0e0d_100f,
+        );
+        assert_eq!(0x00ffi16 * Bb.swapbytes16, -0x100);
+        assert_eq!(0x1234_5678u32 * Bb.swapbytes16.swap16, 0x1234_5678u32.swap_bytes());
+    }
+
+    #[test]
+    fn swap16() {
+        assert_eq!(0x1111_2222u32 * Bb.swap16, 0x2222_1111);
+        assert_eq!(0x1234_5678u32 * Bb.swap16, 0x5678_1234);
+        assert_eq!(0x1111_2222_3333_4444u64 * Bb.swap16, 0x4444_3333_2222_1111);
+        assert_eq!(0x0102_0304_0506_0708u64 * Bb.swap16, 0x0708_0506_0304_0102);
+        assert_eq!(
+            0x0001_0002_0003_0004_0005_0006_0007_0008u128 * Bb.swap16,
+            0x0008_0007_0006_0005_0004_0003_0002_0001,
+        );
+        assert_eq!(0x0000_ffffi32 * Bb.swap16, -0x1_0000);
+        assert_eq!(-1i64 * Bb.swap16, -1);
+    }
+
+    #[test]
+    fn swap32() {
+        assert_eq!(0x1111_1111_2222_2222u64 * Bb.swap32, 0x2222_2222_1111_1111);
+        let x = 0x0102_0304_0506_0708u64;
+        assert_eq!(x * Bb.swap32, 0x0506_0708_0102_0304);
+        assert_ne!(x * Bb.swap32, x.swap_bytes());
+        assert_eq!(
+            0x0000_0001_0000_0002_0000_0003_0000_0004u128 * Bb.swap32,
+            0x0000_0004_0000_0003_0000_0002_0000_0001,
+        );
+        assert_eq!(0xffff_ffffi64 * Bb.swap32, -0x1_0000_0000);
+    }
+
+    #[test]
+    fn swapnib() {
+        assert_eq!(0xabu8 * Bb.swapnib, 0xba);
+        assert_eq!(0x1234_5678u32 * Bb.swapnib, 0x2143_6587);
+        assert_eq!(0x0fi8 * Bb.swapnib, -0x10);
+        assert_eq!(u128::MAX / 0xff * 0x1e * Bb.swapnib, u128::MAX / 0xff * 0xe1);
+        assert_eq!(0xabcdu16 * Bb.swapnib.swapnib, 0xabcd);
+    }
+
+    #[test]
+    fn reflect8() {
+        for x in 0..=255u8 {
+            assert_eq!(x * Bb.reflect8, x.reverse_bits());
+        }
+        assert_eq!(0x0180_c0e0u32 * Bb.reflect8, 0x8001_0307);
+        assert_eq!(0x0102_0304_0506_0708u64 * Bb.reflect8, 0x8040_c020_a060_e010);
+        assert_eq!(0x0001i16 * Bb.reflect8, 0x0080);
+        assert_eq!(0x0100i16 * Bb.reflect8, -0x8000);
+    }
+
+    #[test]
+    fn ones8() {
+        assert_eq!(0xff00u16 * Bb.ones8, 0x0800);
+        assert_eq!(0x0f01u16 * Bb.ones8, 0x0401);
+        assert_eq!(0xff7f_0300u32 * Bb.ones8, 0x0807_0200);
+        assert_eq!(-1i32 * Bb.ones8, 0x0808_0808);
+        for x in 0..=255u8 {
+            assert_eq!(x * Bb.ones8, x.count_ones() as u8);
+        }
+        let x = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        let expected = u128::from_ne_bytes(x.to_ne_bytes().map(|b| b.count_ones() as u8));
+        assert_eq!(x * Bb.ones8, expected);
+    }
+
+    #[test]
+    fn revnib() {
+        assert_eq!(0x1234u16 * Bb.revnib, 0x4321);
+        assert_eq!(0x12u8 * Bb.revnib, 0x21);
+        assert_eq!(0x1234_5678u32 * Bb.revnib, 0x8765_4321);
+        assert_eq!(0x0123_4567_89ab_cdefu64 * Bb.revnib, 0xfedc_ba98_7654_3210);
+        assert_eq!(1u128 * Bb.revnib, 1 << 124);
+        assert_eq!(0x000fi16 * Bb.revnib, -0x1000);
+        for x in [0u32, 1, 0xdead_beef, u32::MAX] {
+            assert_eq!(x * Bb.revnib.revnib, x);
+        }
+    }
+}
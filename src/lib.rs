@@ -1,12 +1,52 @@
 //! Yet another bitcast API endeavour.
 //! 
-//! See [Bb] for more information.
+//! See [Bb](struct@Bb) for more information.
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::marker::PhantomData;
 use core::ops::Deref;
 use core::ops::Mul;
 
+mod arith;
+mod atomic;
+mod bits;
+mod bytes;
+mod convert;
+pub mod chain;
+mod digits;
+mod endian;
+mod enums;
+mod fallible;
+mod fields;
+mod float;
+mod forward;
+mod inspect;
+#[cfg(feature = "const")]
+mod konst;
+#[cfg(feature = "alloc")]
+mod iter;
+mod lanes;
+mod repr;
+mod table;
+
+pub use arith::{AbsDiff, AddSigned, Align, Midpoint, Promote, SumInto, WideSum, WideningMul};
+pub use atomic::AtomicCast;
+pub use bits::{BitCounts, BitIter, BitPermute, BitScan, BitSelect, CarrylessMul, CollapseRuns, CommonPrefix, Denormalize, Hamming, LowMask, PartialReverse, PopLowest, RotateCarry, SpreadBits};
+pub use bytes::{FindByte, InterleaveBytes, ReadBytes, RotateBytes, WriteBytes};
+pub use convert::{BitcastError, SaturatingFrom};
+pub use digits::{Bcd, Hex};
+pub use endian::{Big, ByteLanes, ByteOrder, Endian, Little, Native};
+pub use enums::BbRepr;
+pub use fields::{Bits, Fit, PackBools, SignExtend, ZeroExtend};
+pub use float::{F16Input, FloatToInt, IsFiniteInput, IsNanInput, IsSubnormalInput, NanError, NanPolicy, NanZero, Ulps};
+#[cfg(feature = "alloc")]
+pub use iter::CastIterExt;
+#[cfg(feature = "derive")]
+pub use yabe_derive::{Bb, BbRepr};
+
 /// Bit bashing struct.
 /// 
 /// `x: int ⇒ x * Bb = x`.
@@ -18,7 +58,90 @@ use core::ops::Mul;
 /// - `Bb.u` — change type to its unsigned counterpart.
 /// - `Bb.t{width}` — truncate to a type with equivalent signedness and specified bit width.
 /// - `Bb.e{width}` — extend to a type with equivalent signedness and specified bit width.
-/// Depending on type signedness, this can be either zero (unsigned) or signed extension.
+///   Depending on type signedness, this can be either zero (unsigned) or signed extension.
+/// - `Bb.pdp` — convert a 32-bit value from native to PDP-endian (2-1-4-3) byte order.
+/// - `Bb.unpdp` — convert a 32-bit value from PDP-endian to native byte order.
+/// - `Bb.swap16` — reverse the order of 16-bit lanes, keeping the byte order within each lane.
+/// - `Bb.swap32` — reverse the order of 32-bit lanes, keeping the byte order within each lane.
+/// - `Bb.swapbytes16` — swap the bytes within each 16-bit lane, keeping the lane order.
+/// - `Bb.swapnib` — swap the nibbles within each byte.
+/// - `Bb.reflect8` — reverse the bits within each byte, keeping the byte order.
+/// - `Bb.blsr` — clear the lowest set bit.
+/// - `Bb.blsmsk` — set all bits up to and including the lowest set bit, clear the rest.
+/// - `Bb.rev` — reverse the order of all bits.
+/// - `Bb.id` — leave the value as is.
+/// - `Bb.ct{width}` — truncate like `Bb.t{width}`, producing `None` if the value doesn't fit.
+/// - `Bb.exact` — make the following truncation `debug_assert!` that the value fits.
+/// - `Bb.ones8` — replace each byte with the number of its set bits.
+/// - `Bb.ones_comp` — negate a signed value in one's complement representation.
+/// - `Bb.to_twos` — convert a signed value from one's complement to two's complement representation.
+///   Negative zero (all ones) becomes zero.
+/// - `Bb.from_twos` — convert a signed value from two's complement to one's complement representation.
+///   `MIN` has no such representation and saturates, so it becomes `-MAX`.
+/// - `Bb.to_sm` — convert a signed value to sign-magnitude representation, as the unsigned type of same width.
+///   The magnitude of `MIN` doesn't fit and saturates, so it becomes `-MAX`.
+/// - `Bb.from_sm` — convert an unsigned sign-magnitude value to a signed one. Negative zero becomes zero.
+/// - `Bb.ilog2` — base 2 logarithm rounded down, as `u32`. Panics on zero and negative values.
+/// - `Bb.ilog10` — base 10 logarithm rounded down, as `u32`. Panics on zero and negative values.
+/// - `Bb.checked_ilog2` — like `Bb.ilog2`, producing `None` instead of panicking.
+/// - `Bb.isqrt` — integer square root, rounded down. Panics on negative values.
+/// - `Bb.norm_msb` — shift left until the highest set bit is the most significant one, producing `(value, shift)`. Zero stays zero with shift 0.
+/// - `Bb.sneg` — negate a signed value, saturating `-MIN` to `MAX`.
+/// - `Bb.revnib` — reverse the order of all nibbles.
+/// - `Bb.ones` — number of set bits, as `u32`.
+/// - `Bb.widen2` — extend to the type of double width and equivalent signedness. 128-bit types can't be widened.
+/// - `Bb.narrow2` — truncate to the type of half width and equivalent signedness. 8-bit types can't be narrowed.
+/// - `Bb.sat_u` — change type to its unsigned counterpart, clamping negative values to zero.
+/// - `Bb.sat_s` — change type to its signed counterpart, clamping values above the signed maximum to it.
+/// - `Bb.lrun` — length of the run of equal bits starting at the most significant bit, as `u32`.
+/// - `Bb.trun` — length of the run of equal bits starting at the least significant bit, as `u32`.
+/// - `Bb.lowb` — least significant byte, as `u8`.
+/// - `Bb.highb` — most significant byte, as `u8`.
+/// - `Bb.narrow2_sat` — truncate to the type of half width and equivalent signedness, clamping the value into its range.
+/// - `Bb.isnan` — tell whether an `f32` or `f64` is NaN, from its bit pattern.
+/// - `Bb.isfinite` — tell whether an `f32` or `f64` is neither infinite nor NaN, from its bit pattern.
+/// - `Bb.issubnormal` — tell whether an `f32` or `f64` is subnormal, from its bit pattern. Zero isn't subnormal.
+/// - `Bb.nibparity` — replace each nibble with its parity, in the low bit of the nibble.
+/// - `Bb.f16_rne` — narrow an `f32` to the bits of an IEEE 754 half precision float as `u16`, rounding to nearest with ties to even.
+/// - `Bb.f16_rtz` — like `Bb.f16_rne`, rounding towards zero. Values too large for a finite half become its maximum instead of infinity.
+/// 
+/// ### Methods
+/// - `Bb.truncate_to::<T>()` — truncate to `T`, which must not be wider than the source.
+/// - `Bb.extend_to::<T>()` — extend to `T`, which must not be narrower than the source.
+/// - `Bb.sat::<T>()` — convert to `T`, clamping the value into the range of `T`.
+/// - `Bb.trunc::<N>()` — keep the low `N` bits as the smallest unsigned type that holds them. `N` must not exceed the source width.
+/// - `Bb.wrapping_to::<T>()` — convert to `T` like `as` does, truncating or extending as needed.
+/// - `Bb.try_to::<T>()` — convert to `T`, producing [BitcastError] if the value isn't representable in `T`.
+/// - `Bb.endian::<E>()` — convert from native byte order to [Big], [Little] or [Native] endianness.
+/// - `Bb.swap_if::<E>()` — swap bytes unless the target platform is `E` endian.
+/// - `Bb.rev_if::<E>()` — reverse the order of all bits if `E` is big endian.
+/// 
+/// Methods taking runtime arguments end the chain: no fields may follow them.
+/// - `Bb.shls(n)` — shift left by `n`, saturating to `MAX` or `MIN` instead of losing bits.
+/// - `Bb.clampbits(n)` — clamp into the range of an `n`-bit unsigned integer, keeping the type.
+/// - `Bb.rotbytes_in::<LANE>(n)` — rotate the bytes within each `LANE`-byte lane left by `n`.
+/// - `Bb.rtrunc(n)` — reverse the order of all bits, then keep the low `n` bits.
+/// - `Bb.fence(lo, hi)` — keep bits `lo..hi` in place, clearing the rest.
+/// - `Bb.deposit(lo, len, value)` — write the low `len` bits of `value` into bits `lo..lo + len`.
+/// - `Bb.shift(n)` — shift left by a signed `n`, or right if `n` is negative, discarding bits past the width.
+/// - `Bb.cshl(n)` and `Bb.cshr(n)` — shift by `n`, producing `None` if `n` isn't less than the bit width.
+/// - `Bb.checked_shl_for::<T>(n)` — shift left by `n`, producing `None` if `n` isn't less than the bit width of `T`.
+/// - `Bb.divceil(n)` — divide an unsigned value by `n`, rounding up.
+/// - `Bb.nextmul(n)` — round an unsigned value up to a multiple of `n`.
+/// - `Bb.aligned(n)` — tell whether the value is a multiple of `n`, a power of two.
+/// - `Bb.asr_round(n)` — shift right by `n`, rounding to nearest with ties to even.
+/// - `Bb.and_then(f)` — continue a chain producing `Option` or `Result` with a fallible closure.
+/// - `Bb.also(f)` — call `f` with a reference to the value so far and pass the value on, for debugging.
+/// 
+/// Chains are values of the nameable types in [chain], built from [RL] and [RA] links.
+/// `chain.apply(x)` is the same as `x * chain`, which reads better inside closures.
+/// Chains applied to `Option` or `Result` apply to the contained value, if any,
+/// and chains applied to [`Saturating`](core::num::Saturating) apply to the wrapped value with their usual semantics.
+/// 
+/// Lookup tables of chains applied to every index can be built in `const` with [bb_table].
+/// With the `const` feature, single `s`, `u`, `t{width}` and `e{width}` steps are also available
+/// as `const fn`s named after the step and the source type, like `Bb::s_u8` or `Bb::t8_u32`.
+/// Longer chains in `const` nest these calls, or use [bb_table].
 /// 
 /// ### Examples
 /// 
@@ -29,173 +152,929 @@ use core::ops::Mul;
 /// ```
 pub struct Bb;
 
-impl Deref for Bb {
-    type Target = CastSigned<Bb>;
+impl Deref for Bb {
+    type Target = CastSigned<Bb>;
+
+    fn deref(&self) -> &Self::Target {
+        &CastSigned {
+            s: RL(PhantomData)
+        }
+    }
+}
+
+/// Chain link applying the operation `Op` after the chain `Prev`, which is [Bb](struct@Bb) for the first link.
+///
+/// Fields and methods without runtime arguments produce these links, so further ones may follow.
+/// Links are zero sized and `Copy`. Their nameable aliases are in [chain].
+pub struct RL<Op, Prev>(PhantomData<(Op, Prev)>);
+
+impl<Op, Prev> RL<Op, Prev> {
+    /// Applies the chain to `x`. Same as `x * chain`, in method form for closures and combinators.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// let v = [0x1234u16, 0xff80];
+    /// let mut it = v.iter().copied().map(|x| Bb.t8.apply(x));
+    /// assert_eq!(it.next(), Some(0x34));
+    /// assert_eq!(it.next(), Some(0x80));
+    /// ```
+    pub fn apply<X: Mul<Self>>(self, x: X) -> X::Output {
+        x * self
+    }
+}
+
+impl<Op, Prev> Copy for RL<Op, Prev> {}
+
+impl<Op, Prev> Clone for RL<Op, Prev> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// Chain link applying the operation `Op` after the chain `Prev`, carrying the runtime arguments of `Op`.
+///
+/// Methods with runtime arguments produce these links, and nothing may follow them.
+/// A link is as large as its arguments, and `Copy` only if they are: closures given to
+/// `and_then` or `also` may not be.
+pub struct RA<Op, Prev>(Op, PhantomData<Prev>);
+
+impl<Op, Prev> RA<Op, Prev> {
+    /// Applies the chain to `x`. Same as `x * chain`.
+    pub fn apply<X: Mul<Self>>(self, x: X) -> X::Output {
+        x * self
+    }
+}
+
+impl<Op: Copy, Prev> Copy for RA<Op, Prev> {}
+
+impl<Op: Copy, Prev> Clone for RA<Op, Prev> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[doc(hidden)]
+pub struct CastSigned<U = ()> {
+    pub s: RL<CastSigned, U>,
+}
+
+impl<T, U> Deref for RL<T, U> {
+    type Target = CastSigned<Self>;
+
+    fn deref(&self) -> &Self::Target {
+        &CastSigned {
+            s: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CastUnsigned<U = ()> {
+    pub u: RL<CastUnsigned, U>,
+}
+
+impl<U> CastSigned<U> {
+    /// Truncates to `T`, keeping `T`'s signedness. `T` must not be wider than the source.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(-2i128 * Bb.truncate_to::<i16>(), -2i16);
+    /// assert_eq!(0x0123_4567i32 * Bb.truncate_to::<u16>(), 0x4567u16);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use yabe::Bb;
+    /// let _ = 5i8 * Bb.truncate_to::<i32>();
+    /// ```
+    pub fn truncate_to<T>(&self) -> RL<TruncateTo<T>, U> {
+        RL(PhantomData)
+    }
+
+    /// Extends to `T`, sign or zero extending by the source signedness. `T` must not be narrower than the source.
+    ///
+    /// ```
+    /// # use yabe::Bb;
+    /// assert_eq!(-1i8 * Bb.extend_to::<i64>(), -1i64);
+    /// assert_eq!(-1i8 * Bb.extend_to::<u16>(), 0xffffu16);
+    /// assert_eq!(0xffu8 * Bb.extend_to::<i32>(), 255i32);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use yabe::Bb;
+    /// let _ = 5i32 * Bb.extend_to::<i8>();
+    /// ```
+    pub fn extend_to<T>(&self) -> RL<ExtendTo<T>, U> {
+        RL(PhantomData)
+    }
+}
+
+impl<U> Deref for CastSigned<U> {
+    type Target = CastUnsigned<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &CastUnsigned {
+            u: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct TruncateTo<T>(PhantomData<T>);
+
+#[doc(hidden)]
+pub struct ExtendTo<T>(PhantomData<T>);
+
+#[doc(hidden)]
+pub struct Truncate8<U = ()> {
+    pub t8: RL<Truncate8, U>,
+}
+
+#[doc(hidden)]
+pub struct Truncate16<U = ()> {
+    pub t16: RL<Truncate16, U>,
+}
+
+#[doc(hidden)]
+pub struct Truncate32<U = ()> {
+    pub t32: RL<Truncate32, U>,
+}
+
+#[doc(hidden)]
+pub struct Truncate64<U = ()> {
+    pub t64: RL<Truncate64, U>,
+}
+
+#[doc(hidden)]
+pub struct Extend64<U = ()> {
+    pub e64: RL<Extend64, U>,
+}
+
+#[doc(hidden)]
+pub struct Extend32<U = ()> {
+    pub e32: RL<Extend32, U>,
+}
+
+#[doc(hidden)]
+pub struct Extend16<U = ()> {
+    pub e16: RL<Extend16, U>,
+}
+
+#[doc(hidden)]
+pub struct Extend128<U = ()> {
+    pub e128: RL<Extend128, U>,
+}
+
+impl<U> Deref for CastUnsigned<U> {
+    type Target = Truncate8<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Truncate8 {
+            t8: RL(PhantomData),
+        }
+    }
+}
+
+impl<U> Deref for Truncate8<U> {
+    type Target = Extend64<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Extend64 {
+            e64: RL(PhantomData),
+        }
+    }
+}
+
+impl<U> Deref for Extend64<U> {
+    type Target = Truncate16<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Truncate16 {
+            t16: RL(PhantomData),
+        }
+    }
+}
+
+impl<U> Deref for Truncate16<U> {
+    type Target = Extend32<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Extend32 {
+            e32: RL(PhantomData),
+        }
+    }
+}
+
+impl<U> Deref for Extend32<U> {
+    type Target = Truncate32<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Truncate32 {
+            t32: RL(PhantomData),
+        }
+    }
+}
+
+impl<U> Deref for Truncate32<U> {
+    type Target = Extend16<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Extend16 {
+            e16: RL(PhantomData),
+        }
+    }
+}
+
+impl<U> Deref for Extend16<U> {
+    type Target = Truncate64<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Truncate64 {
+            t64: RL(PhantomData),
+        }
+    }
+}
+
+impl<U> Deref for Truncate64<U> {
+    type Target = Extend128<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Extend128 {
+            e128: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ToPdp<U = ()> {
+    pub pdp: RL<ToPdp, U>,
+}
+
+impl<U> Deref for Extend128<U> {
+    type Target = ToPdp<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &ToPdp {
+            pdp: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct FromPdp<U = ()> {
+    pub unpdp: RL<FromPdp, U>,
+}
+
+impl<U> Deref for ToPdp<U> {
+    type Target = FromPdp<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &FromPdp {
+            unpdp: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct Swap16Lanes<U = ()> {
+    pub swap16: RL<Swap16Lanes, U>,
+}
+
+impl<U> Deref for FromPdp<U> {
+    type Target = Swap16Lanes<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Swap16Lanes {
+            swap16: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct Swap32Lanes<U = ()> {
+    pub swap32: RL<Swap32Lanes, U>,
+}
+
+impl<U> Deref for Swap16Lanes<U> {
+    type Target = Swap32Lanes<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Swap32Lanes {
+            swap32: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct SwapBytes16<U = ()> {
+    pub swapbytes16: RL<SwapBytes16, U>,
+}
+
+impl<U> Deref for Swap32Lanes<U> {
+    type Target = SwapBytes16<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &SwapBytes16 {
+            swapbytes16: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct SwapNibbles<U = ()> {
+    pub swapnib: RL<SwapNibbles, U>,
+}
+
+impl<U> Deref for SwapBytes16<U> {
+    type Target = SwapNibbles<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &SwapNibbles {
+            swapnib: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct Reflect8<U = ()> {
+    pub reflect8: RL<Reflect8, U>,
+}
+
+impl<U> Deref for SwapNibbles<U> {
+    type Target = Reflect8<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Reflect8 {
+            reflect8: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ClearLowest<U = ()> {
+    pub blsr: RL<ClearLowest, U>,
+}
+
+impl<U> Deref for Reflect8<U> {
+    type Target = ClearLowest<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &ClearLowest {
+            blsr: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct MaskToLowest<U = ()> {
+    pub blsmsk: RL<MaskToLowest, U>,
+}
+
+impl<U> Deref for ClearLowest<U> {
+    type Target = MaskToLowest<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &MaskToLowest {
+            blsmsk: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ReverseBits<U = ()> {
+    pub rev: RL<ReverseBits, U>,
+}
+
+impl<U> Deref for MaskToLowest<U> {
+    type Target = ReverseBits<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &ReverseBits {
+            rev: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct Identity<U = ()> {
+    pub id: RL<Identity, U>,
+}
+
+impl<U> Deref for ReverseBits<U> {
+    type Target = Identity<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Identity {
+            id: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CheckedTruncate8<U = ()> {
+    pub ct8: RL<CheckedTruncate8, U>,
+}
+
+impl<U> Deref for Identity<U> {
+    type Target = CheckedTruncate8<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &CheckedTruncate8 {
+            ct8: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CheckedTruncate16<U = ()> {
+    pub ct16: RL<CheckedTruncate16, U>,
+}
+
+impl<U> Deref for CheckedTruncate8<U> {
+    type Target = CheckedTruncate16<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &CheckedTruncate16 {
+            ct16: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CheckedTruncate32<U = ()> {
+    pub ct32: RL<CheckedTruncate32, U>,
+}
+
+impl<U> Deref for CheckedTruncate16<U> {
+    type Target = CheckedTruncate32<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &CheckedTruncate32 {
+            ct32: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CheckedTruncate64<U = ()> {
+    pub ct64: RL<CheckedTruncate64, U>,
+}
+
+impl<U> Deref for CheckedTruncate32<U> {
+    type Target = CheckedTruncate64<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &CheckedTruncate64 {
+            ct64: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct Exact<U = ()> {
+    pub exact: RL<Exact, U>,
+}
+
+impl<U> Deref for CheckedTruncate64<U> {
+    type Target = Exact<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Exact {
+            exact: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct PopcountPerByte<U = ()> {
+    pub ones8: RL<PopcountPerByte, U>,
+}
+
+impl<U> Deref for Exact<U> {
+    type Target = PopcountPerByte<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &PopcountPerByte {
+            ones8: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct OnesComplement<U = ()> {
+    pub ones_comp: RL<OnesComplement, U>,
+}
+
+impl<U> Deref for PopcountPerByte<U> {
+    type Target = OnesComplement<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &OnesComplement {
+            ones_comp: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct OnesToTwos<U = ()> {
+    pub to_twos: RL<OnesToTwos, U>,
+}
+
+impl<U> Deref for OnesComplement<U> {
+    type Target = OnesToTwos<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &OnesToTwos {
+            to_twos: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct TwosToOnes<U = ()> {
+    pub from_twos: RL<TwosToOnes, U>,
+}
+
+impl<U> Deref for OnesToTwos<U> {
+    type Target = TwosToOnes<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &TwosToOnes {
+            from_twos: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ToSignMagnitude<U = ()> {
+    pub to_sm: RL<ToSignMagnitude, U>,
+}
+
+impl<U> Deref for TwosToOnes<U> {
+    type Target = ToSignMagnitude<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &ToSignMagnitude {
+            to_sm: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct FromSignMagnitude<U = ()> {
+    pub from_sm: RL<FromSignMagnitude, U>,
+}
+
+impl<U> Deref for ToSignMagnitude<U> {
+    type Target = FromSignMagnitude<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &FromSignMagnitude {
+            from_sm: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct Ilog2<U = ()> {
+    pub ilog2: RL<Ilog2, U>,
+}
+
+impl<U> Deref for FromSignMagnitude<U> {
+    type Target = Ilog2<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Ilog2 {
+            ilog2: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct Ilog10<U = ()> {
+    pub ilog10: RL<Ilog10, U>,
+}
+
+impl<U> Deref for Ilog2<U> {
+    type Target = Ilog10<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Ilog10 {
+            ilog10: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CheckedIlog2<U = ()> {
+    pub checked_ilog2: RL<CheckedIlog2, U>,
+}
+
+impl<U> Deref for Ilog10<U> {
+    type Target = CheckedIlog2<U>;
 
     fn deref(&self) -> &Self::Target {
-        &CastSigned {
-            s: RL(PhantomData)
+        &CheckedIlog2 {
+            checked_ilog2: RL(PhantomData),
         }
     }
 }
 
 #[doc(hidden)]
-pub struct RL<T, U>(PhantomData<(T, U)>);
+pub struct Isqrt<U = ()> {
+    pub isqrt: RL<Isqrt, U>,
+}
 
-impl<T, U> Copy for RL<T, U> {}
+impl<U> Deref for CheckedIlog2<U> {
+    type Target = Isqrt<U>;
 
-impl<T, U> Clone for RL<T, U> {
-    fn clone(&self) -> Self {
-        *self
+    fn deref(&self) -> &Self::Target {
+        &Isqrt {
+            isqrt: RL(PhantomData),
+        }
     }
 }
 
 #[doc(hidden)]
-pub struct CastSigned<U = ()> {
-    pub s: RL<CastSigned, U>,
+pub struct NormMsb<U = ()> {
+    pub norm_msb: RL<NormMsb, U>,
 }
 
-impl<T, U> Deref for RL<T, U> {
-    type Target = CastSigned<Self>;
+impl<U> Deref for Isqrt<U> {
+    type Target = NormMsb<U>;
 
     fn deref(&self) -> &Self::Target {
-        &CastSigned {
-            s: RL(PhantomData),
+        &NormMsb {
+            norm_msb: RL(PhantomData),
         }
     }
 }
 
 #[doc(hidden)]
-pub struct CastUnsigned<U = ()> {
-    pub u: RL<CastUnsigned, U>,
+pub struct SaturatingNeg<U = ()> {
+    pub sneg: RL<SaturatingNeg, U>,
 }
 
-impl<U> Deref for CastSigned<U> {
-    type Target = CastUnsigned<U>;
+impl<U> Deref for NormMsb<U> {
+    type Target = SaturatingNeg<U>;
 
     fn deref(&self) -> &Self::Target {
-        &CastUnsigned {
-            u: RL(PhantomData),
+        &SaturatingNeg {
+            sneg: RL(PhantomData),
         }
     }
 }
 
 #[doc(hidden)]
-pub struct Truncate8<U = ()> {
-    pub t8: RL<Truncate8, U>,
+pub struct ReverseNibbles<U = ()> {
+    pub revnib: RL<ReverseNibbles, U>,
+}
+
+impl<U> Deref for SaturatingNeg<U> {
+    type Target = ReverseNibbles<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &ReverseNibbles {
+            revnib: RL(PhantomData),
+        }
+    }
 }
 
 #[doc(hidden)]
-pub struct Truncate16<U = ()> {
-    pub t16: RL<Truncate16, U>,
+pub struct CountOnes<U = ()> {
+    pub ones: RL<CountOnes, U>,
+}
+
+impl<U> Deref for ReverseNibbles<U> {
+    type Target = CountOnes<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &CountOnes {
+            ones: RL(PhantomData),
+        }
+    }
 }
 
 #[doc(hidden)]
-pub struct Truncate32<U = ()> {
-    pub t32: RL<Truncate32, U>,
+pub struct Widen2<U = ()> {
+    pub widen2: RL<Widen2, U>,
+}
+
+impl<U> Deref for CountOnes<U> {
+    type Target = Widen2<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Widen2 {
+            widen2: RL(PhantomData),
+        }
+    }
 }
 
 #[doc(hidden)]
-pub struct Truncate64<U = ()> {
-    pub t64: RL<Truncate64, U>,
+pub struct Narrow2<U = ()> {
+    pub narrow2: RL<Narrow2, U>,
+}
+
+impl<U> Deref for Widen2<U> {
+    type Target = Narrow2<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &Narrow2 {
+            narrow2: RL(PhantomData),
+        }
+    }
 }
 
 #[doc(hidden)]
-pub struct Extend64<U = ()> {
-    pub e64: RL<Extend64, U>,
+pub struct SatU<U = ()> {
+    pub sat_u: RL<SatU, U>,
+}
+
+impl<U> Deref for Narrow2<U> {
+    type Target = SatU<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &SatU {
+            sat_u: RL(PhantomData),
+        }
+    }
 }
 
 #[doc(hidden)]
-pub struct Extend32<U = ()> {
-    pub e32: RL<Extend32, U>,
+pub struct SatS<U = ()> {
+    pub sat_s: RL<SatS, U>,
+}
+
+impl<U> Deref for SatU<U> {
+    type Target = SatS<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &SatS {
+            sat_s: RL(PhantomData),
+        }
+    }
 }
 
 #[doc(hidden)]
-pub struct Extend16<U = ()> {
-    pub e16: RL<Extend16, U>,
+pub struct LeadingRun<U = ()> {
+    pub lrun: RL<LeadingRun, U>,
+}
+
+impl<U> Deref for SatS<U> {
+    type Target = LeadingRun<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &LeadingRun {
+            lrun: RL(PhantomData),
+        }
+    }
 }
 
 #[doc(hidden)]
-pub struct Extend128<U = ()> {
-    pub e128: RL<Extend128, U>,
+pub struct TrailingRun<U = ()> {
+    pub trun: RL<TrailingRun, U>,
 }
 
-impl<U> Deref for CastUnsigned<U> {
-    type Target = Truncate8<U>;
+impl<U> Deref for LeadingRun<U> {
+    type Target = TrailingRun<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Truncate8 {
-            t8: RL(PhantomData),
+        &TrailingRun {
+            trun: RL(PhantomData),
         }
     }
 }
 
-impl<U> Deref for Truncate8<U> {
-    type Target = Extend64<U>;
+#[doc(hidden)]
+pub struct LowByte<U = ()> {
+    pub lowb: RL<LowByte, U>,
+}
+
+impl<U> Deref for TrailingRun<U> {
+    type Target = LowByte<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Extend64 {
-            e64: RL(PhantomData),
+        &LowByte {
+            lowb: RL(PhantomData),
         }
     }
 }
 
-impl<U> Deref for Extend64<U> {
-    type Target = Truncate16<U>;
+#[doc(hidden)]
+pub struct HighByte<U = ()> {
+    pub highb: RL<HighByte, U>,
+}
+
+impl<U> Deref for LowByte<U> {
+    type Target = HighByte<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Truncate16 {
-            t16: RL(PhantomData),
+        &HighByte {
+            highb: RL(PhantomData),
         }
     }
 }
 
-impl<U> Deref for Truncate16<U> {
-    type Target = Extend32<U>;
+#[doc(hidden)]
+pub struct Narrow2Sat<U = ()> {
+    pub narrow2_sat: RL<Narrow2Sat, U>,
+}
+
+impl<U> Deref for HighByte<U> {
+    type Target = Narrow2Sat<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Extend32 {
-            e32: RL(PhantomData),
+        &Narrow2Sat {
+            narrow2_sat: RL(PhantomData),
         }
     }
 }
 
-impl<U> Deref for Extend32<U> {
-    type Target = Truncate32<U>;
+#[doc(hidden)]
+pub struct IsNan<U = ()> {
+    pub isnan: RL<IsNan, U>,
+}
+
+impl<U> Deref for Narrow2Sat<U> {
+    type Target = IsNan<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Truncate32 {
-            t32: RL(PhantomData),
+        &IsNan {
+            isnan: RL(PhantomData),
         }
     }
 }
 
-impl<U> Deref for Truncate32<U> {
-    type Target = Extend16<U>;
+#[doc(hidden)]
+pub struct IsFinite<U = ()> {
+    pub isfinite: RL<IsFinite, U>,
+}
+
+impl<U> Deref for IsNan<U> {
+    type Target = IsFinite<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Extend16 {
-            e16: RL(PhantomData),
+        &IsFinite {
+            isfinite: RL(PhantomData),
         }
     }
 }
 
-impl<U> Deref for Extend16<U> {
-    type Target = Truncate64<U>;
+#[doc(hidden)]
+pub struct IsSubnormal<U = ()> {
+    pub issubnormal: RL<IsSubnormal, U>,
+}
+
+impl<U> Deref for IsFinite<U> {
+    type Target = IsSubnormal<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Truncate64 {
-            t64: RL(PhantomData),
+        &IsSubnormal {
+            issubnormal: RL(PhantomData),
         }
     }
 }
 
-impl<U> Deref for Truncate64<U> {
-    type Target = Extend128<U>;
+#[doc(hidden)]
+pub struct NibbleParity<U = ()> {
+    pub nibparity: RL<NibbleParity, U>,
+}
+
+impl<U> Deref for IsSubnormal<U> {
+    type Target = NibbleParity<U>;
 
     fn deref(&self) -> &Self::Target {
-        &Extend128 {
-            e128: RL(PhantomData),
+        &NibbleParity {
+            nibparity: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct F16Rne<U = ()> {
+    pub f16_rne: RL<F16Rne, U>,
+}
+
+impl<U> Deref for NibbleParity<U> {
+    type Target = F16Rne<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &F16Rne {
+            f16_rne: RL(PhantomData),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct F16Rtz<U = ()> {
+    pub f16_rtz: RL<F16Rtz, U>,
+}
+
+impl<U> Deref for F16Rne<U> {
+    type Target = F16Rtz<U>;
+
+    fn deref(&self) -> &Self::Target {
+        &F16Rtz {
+            f16_rtz: RL(PhantomData),
         }
     }
 }
@@ -225,7 +1104,7 @@ macro_rules! impl_id {
     };
 }
 
-impl_id!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128);
+impl_id!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64);
 
 #[doc(hidden)]
 pub trait MkSigned {
@@ -276,49 +1155,74 @@ macro_rules! impl_su {
                     self
                 }
             }
+        )*
+    };
+}
 
-            impl<U> Mul<RL<CastSigned, U>> for $S where $S: Mul<U>, MulT<$S, U>: MkSigned {
-                type Output = <MulT<$S, U> as MkSigned>::Signed;
+impl_su!(i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
 
-                fn mul(self, _: RL<CastSigned, U>) -> Self::Output {
-                    (self * unsafe { make_zst::<U>() }).cast_signed()
-                }
-            }
+#[doc(hidden)]
+pub trait AppliedTo<Src> {
+    type Output;
 
-            impl<U> Mul<RL<CastSigned, U>> for $U where $U: Mul<U>, MulT<$U, U>: MkSigned {
-                type Output = <MulT<$U, U> as MkSigned>::Signed;
+    fn apply(src: Src) -> Self::Output;
+}
 
-                fn mul(self, _: RL<CastSigned, U>) -> Self::Output {
-                    (self * unsafe { make_zst::<U>() }).cast_signed()
-                }
-            }
+#[doc(hidden)]
+pub trait AppliedWith<Src> {
+    type Output;
 
-            impl<U> Mul<RL<CastUnsigned, U>> for $S where $S: Mul<U>, MulT<$S, U>: MkUnsigned {
-                type Output = <MulT<$S, U> as MkUnsigned>::Unsigned;
+    fn apply_with(self, src: Src) -> Self::Output;
+}
+
+/// Every chain link defers to its operation's [AppliedTo] or [AppliedWith] impl, once the link's prefix has been evaluated.
+#[doc(hidden)]
+macro_rules! impl_chain {
+    ($($T:ty)*) => {
+        $(
+            impl<Op, U> Mul<RL<Op, U>> for $T where $T: Mul<U>, Op: AppliedTo<MulT<$T, U>> {
+                type Output = <Op as AppliedTo<MulT<$T, U>>>::Output;
 
-                fn mul(self, _: RL<CastUnsigned, U>) -> Self::Output {
-                    (self * unsafe { make_zst::<U>() }).cast_unsigned()
+                fn mul(self, _: RL<Op, U>) -> Self::Output {
+                    Op::apply(self * unsafe { make_zst::<U>() })
                 }
             }
 
-            impl<U> Mul<RL<CastUnsigned, U>> for $U where $U: Mul<U>, MulT<$U, U>: MkUnsigned {
-                type Output = <MulT<$U, U> as MkUnsigned>::Unsigned;
+            impl<Op, U> Mul<RA<Op, U>> for $T where $T: Mul<U>, Op: AppliedWith<MulT<$T, U>> {
+                type Output = <Op as AppliedWith<MulT<$T, U>>>::Output;
 
-                fn mul(self, _: RL<CastUnsigned, U>) -> Self::Output {
-                    (self * unsafe { make_zst::<U>() }).cast_unsigned()
+                fn mul(self, rhs: RA<Op, U>) -> Self::Output {
+                    rhs.0.apply_with(self * unsafe { make_zst::<U>() })
                 }
             }
         )*
     };
 }
 
-impl_su!(i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+impl_chain!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64);
 
-#[doc(hidden)]
-pub trait AppliedTo<Src> {
-    type Output;
+impl<T> AppliedTo<T> for Identity {
+    type Output = T;
 
-    fn apply(src: Src) -> Self::Output;
+    fn apply(src: T) -> T {
+        src
+    }
+}
+
+impl<T: MkSigned> AppliedTo<T> for CastSigned {
+    type Output = T::Signed;
+
+    fn apply(src: T) -> T::Signed {
+        src.cast_signed()
+    }
+}
+
+impl<T: MkUnsigned> AppliedTo<T> for CastUnsigned {
+    type Output = T::Unsigned;
+
+    fn apply(src: T) -> T::Unsigned {
+        src.cast_unsigned()
+    }
 }
 
 #[doc(hidden)]
@@ -332,14 +1236,6 @@ macro_rules! applied_to {
                     src as $U
                 }
             }
-
-            impl<U> Mul<RL<$Op, U>> for $T where $T: Mul<U>, $Op: AppliedTo<MulT<$T, U>> {
-                type Output = <$Op as AppliedTo<MulT<$T, U>>>::Output;
-
-                fn mul(self, _: RL<$Op, U>) -> Self::Output {
-                    <$Op>::apply(self * unsafe { make_zst::<U>() })
-                }
-            }
         )*
     };
 }
@@ -353,6 +1249,107 @@ applied_to!(Truncate32 => u32: u64 u128);
 applied_to!(Truncate64 => i64: i128);
 applied_to!(Truncate64 => u64: u128);
 
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(message = "`{Self}` can't be truncated to `{T}`", label = "`{T}` is wider than `{Self}`")]
+pub trait TruncatesTo<T> {
+    fn truncate(self) -> T;
+}
+
+#[doc(hidden)]
+macro_rules! truncates_to {
+    ($($S:ty)* => $T:tt) => {
+        $(
+            truncates_to!(@ $S => $T);
+        )*
+    };
+    (@ $S:ty => [$($T:ty)*]) => {
+        $(
+            impl TruncatesTo<$T> for $S {
+                fn truncate(self) -> $T {
+                    self as $T
+                }
+            }
+        )*
+    };
+}
+
+truncates_to!(i8 u8 => [i8 u8]);
+truncates_to!(i16 u16 => [i8 u8 i16 u16]);
+truncates_to!(i32 u32 => [i8 u8 i16 u16 i32 u32]);
+truncates_to!(i64 u64 => [i8 u8 i16 u16 i32 u32 i64 u64]);
+truncates_to!(i128 u128 => [i8 u8 i16 u16 i32 u32 i64 u64 i128 u128]);
+
+impl<S: TruncatesTo<T>, T> AppliedTo<S> for TruncateTo<T> {
+    type Output = T;
+
+    fn apply(src: S) -> T {
+        src.truncate()
+    }
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(message = "`{Self}` can't be extended to `{T}`", label = "`{T}` is narrower than `{Self}`")]
+pub trait ExtendsTo<T> {
+    fn extend(self) -> T;
+}
+
+#[doc(hidden)]
+macro_rules! extends_to {
+    ($($S:ty)* => $T:tt) => {
+        $(
+            extends_to!(@ $S => $T);
+        )*
+    };
+    (@ $S:ty => [$($T:ty)*]) => {
+        $(
+            impl ExtendsTo<$T> for $S {
+                fn extend(self) -> $T {
+                    self as $T
+                }
+            }
+        )*
+    };
+}
+
+extends_to!(i8 u8 => [i8 u8 i16 u16 i32 u32 i64 u64 i128 u128]);
+extends_to!(i16 u16 => [i16 u16 i32 u32 i64 u64 i128 u128]);
+extends_to!(i32 u32 => [i32 u32 i64 u64 i128 u128]);
+extends_to!(i64 u64 => [i64 u64 i128 u128]);
+extends_to!(i128 u128 => [i128 u128]);
+
+impl<S: ExtendsTo<T>, T> AppliedTo<S> for ExtendTo<T> {
+    type Output = T;
+
+    fn apply(src: S) -> T {
+        src.extend()
+    }
+}
+
+/// Integer conversion with `as` semantics: truncation, sign or zero extension, or a signedness change.
+pub trait CastFrom<Src> {
+    fn cast_from(src: Src) -> Self;
+}
+
+#[doc(hidden)]
+macro_rules! cast_from {
+    ($($S:ty)* => $T:tt) => {
+        $(
+            cast_from!(@ $S => $T);
+        )*
+    };
+    (@ $S:ty => [$($T:ty)*]) => {
+        $(
+            impl CastFrom<$S> for $T {
+                fn cast_from(src: $S) -> $T {
+                    src as $T
+                }
+            }
+        )*
+    };
+}
+
+cast_from!(i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 => [i8 u8 i16 u16 i32 u32 i64 u64 i128 u128]);
+
 // signed extension
 applied_to!(Extend128 => i128: i64 i32 i16 i8);
 applied_to!(Extend64 => i64: i32 i16 i8);
@@ -376,4 +1373,48 @@ mod tests {
         assert_eq!(u64::MAX * Bb.t8, 255);
         assert_eq!(0xfeu8 * Bb.s.e16.u, 0xfffe);
     }
+
+    #[test]
+    fn id() {
+        assert_eq!(5u8 * Bb.id, 5u8);
+        assert_eq!(-1i64 * Bb.id.id, -1i64);
+        assert_eq!(0x1234u16 * Bb.id.t8, 0x34u8);
+        assert_eq!(0x1234u16 * Bb.t8.id, 0x34u8);
+        assert_eq!(-1i8 * Bb.id.u.id.e16.id, 0xff);
+    }
+
+    #[test]
+    fn apply() {
+        let v = [0x1234u16, 0xff80, 0x7f];
+        let mut out = [0u8; 3];
+        for (o, x) in out.iter_mut().zip(v.iter().copied().map(|x| Bb.t8.apply(x))) {
+            *o = x;
+        }
+        assert_eq!(out, [0x34, 0x80, 0x7f]);
+        assert!(v.iter().copied().map(|x| Bb.s.e32.apply(x)).eq([0x1234, -0x80, 0x7f]));
+        let chain = Bb.u.e64;
+        assert_eq!(v.map(|x| chain.apply(x)), v.map(|x| x * chain));
+        assert!(v.iter().copied().map(|x| Bb.e32.shls(20).apply(x)).eq([u32::MAX, u32::MAX, 0x7f0_0000]));
+    }
+
+    #[test]
+    fn truncate_to() {
+        assert_eq!(i128::MIN * Bb.truncate_to::<i16>(), 0);
+        assert_eq!(-0x1_8000i128 * Bb.truncate_to::<i16>(), i16::MIN);
+        assert_eq!(0x1234_5678u32 * Bb.truncate_to::<u8>(), 0x78);
+        assert_eq!(0xffu8 * Bb.truncate_to::<i8>(), -1);
+        assert_eq!(-1i64 * Bb.truncate_to::<u32>().e64, 0xffff_ffff);
+        assert_eq!(0x1234i16 * Bb.u.truncate_to::<u8>(), 0x34);
+    }
+
+    #[test]
+    fn extend_to() {
+        assert_eq!(-1i8 * Bb.extend_to::<i64>(), -1);
+        assert_eq!(-1i8 * Bb.u.extend_to::<i64>(), 255);
+        assert_eq!(i16::MIN * Bb.extend_to::<i128>(), i16::MIN as i128);
+        assert_eq!(0x8000u16 * Bb.extend_to::<u64>(), 0x8000);
+        assert_eq!(u32::MAX * Bb.extend_to::<i64>(), 0xffff_ffff);
+        assert_eq!(-1i32 * Bb.extend_to::<u64>(), u64::MAX);
+        assert_eq!(-1i64 * Bb.t8.extend_to::<u16>(), 0xffff);
+    }
 }
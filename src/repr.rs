@@ -0,0 +1,119 @@
+//! Signed number representations other than two's complement.
+
+use crate::AppliedTo;
+use crate::FromSignMagnitude;
+use crate::OnesComplement;
+use crate::OnesToTwos;
+use crate::ToSignMagnitude;
+use crate::TwosToOnes;
+
+// One's complement negative zero is all ones, which `to_twos` maps to zero.
+// `MIN` has no one's complement representation, so `from_twos` saturates it to `-MAX`, whose bits are `MIN`.
+#[doc(hidden)]
+macro_rules! impl_ones_complement {
+    ($($S:ty)*) => {
+        $(
+            impl AppliedTo<$S> for OnesComplement {
+                type Output = $S;
+
+                fn apply(src: $S) -> $S {
+                    !src
+                }
+            }
+
+            impl AppliedTo<$S> for OnesToTwos {
+                type Output = $S;
+
+                fn apply(src: $S) -> $S {
+                    if src < 0 { src + 1 } else { src }
+                }
+            }
+
+            impl AppliedTo<$S> for TwosToOnes {
+                type Output = $S;
+
+                fn apply(src: $S) -> $S {
+                    if src < 0 { src.saturating_sub(1) } else { src }
+                }
+            }
+        )*
+    };
+}
+
+impl_ones_complement!(i8 i16 i32 i64 i128);
+
+#[doc(hidden)]
+macro_rules! impl_sign_magnitude {
+    ($($S:ty > $U:ty),*) => {
+        $(
+            impl AppliedTo<$S> for ToSignMagnitude {
+                type Output = $U;
+
+                fn apply(src: $S) -> $U {
+                    const SIGN: $U = !(<$S>::MAX as $U);
+                    let mag = if src == <$S>::MIN { <$S>::MAX as $U } else { src.unsigned_abs() };
+                    if src < 0 { SIGN | mag } else { mag }
+                }
+            }
+
+            impl AppliedTo<$U> for FromSignMagnitude {
+                type Output = $S;
+
+                fn apply(src: $U) -> $S {
+                    let mag = (src & <$S>::MAX as $U) as $S;
+                    if src > <$S>::MAX as $U { -mag } else { mag }
+                }
+            }
+        )*
+    };
+}
+
+impl_sign_magnitude!(i8 > u8, i16 > u16, i32 > u32, i64 > u64, i128 > u128);
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    #[test]
+    fn ones_complement() {
+        // -5 in one's complement is !5
+        assert_eq!(5i8 * Bb.ones_comp, !5);
+        assert_eq!(5i8 * Bb.ones_comp.to_twos, -5);
+        assert_eq!(-5i8 * Bb.from_twos, !5);
+        assert_eq!(-5i8 * Bb.from_twos.ones_comp, 5);
+        // negative zero
+        assert_eq!(0i16 * Bb.ones_comp, -1);
+        assert_eq!(-1i16 * Bb.to_twos, 0);
+        assert_eq!(-1i16 * Bb.to_twos.from_twos, 0);
+        assert_eq!(0i16 * Bb.ones_comp.to_twos, 0);
+        for v in (i8::MIN + 1..=i8::MAX).map(|v| v as i32 * 0x0101_0101) {
+            assert_eq!(v * Bb.from_twos.to_twos, v);
+            assert_eq!(v * Bb.from_twos.ones_comp.to_twos, -v);
+        }
+        assert_eq!(i64::MAX * Bb.ones_comp.to_twos, -i64::MAX);
+        assert_eq!(i128::MIN * Bb.to_twos, -i128::MAX);
+        // MIN saturates to -MAX
+        assert_eq!(i8::MIN * Bb.from_twos, i8::MIN);
+        assert_eq!(i8::MIN * Bb.from_twos.to_twos, -i8::MAX);
+        assert_eq!(i8::MIN * Bb.from_twos, -i8::MAX * Bb.from_twos);
+        assert_eq!(i128::MIN * Bb.from_twos.ones_comp, i128::MAX);
+    }
+
+    #[test]
+    fn sign_magnitude() {
+        assert_eq!(0i8 * Bb.to_sm, 0);
+        assert_eq!(-1i8 * Bb.to_sm, 0x81);
+        assert_eq!(i8::MAX * Bb.to_sm, 0x7f);
+        assert_eq!(i8::MIN * Bb.to_sm, 0xff);
+        assert_eq!(-0x1234i16 * Bb.to_sm, 0x9234);
+        assert_eq!(0x80u8 * Bb.from_sm, 0);
+        assert_eq!(0xffu8 * Bb.from_sm, -i8::MAX);
+        assert_eq!(0x9234u16 * Bb.from_sm, -0x1234);
+        for v in [0, -1, i64::MAX, i64::MIN + 1, 0x1234_5678, -0x1234_5678] {
+            assert_eq!(v * Bb.to_sm.from_sm, v);
+        }
+        assert_eq!(i64::MIN * Bb.to_sm.from_sm, -i64::MAX);
+        assert_eq!(i128::MIN * Bb.to_sm, u128::MAX);
+        assert_eq!(u32::MAX * Bb.from_sm.to_sm, u32::MAX);
+    }
+}
@@ -0,0 +1,292 @@
+/// Builds a lookup table of a chain applied to every index, usable in `const` context.
+///
+/// `bb_table!(T => N: chain)` evaluates to an array of `N` elements,
+/// where the element at index `i` is `(i as T) * Bb.chain`.
+///
+/// Supported chain fields are `s`, `u`, `t{width}`, `e{width}`, `rev` and `id`,
+/// as well as the `sat::<T>()` method written without parentheses, as `sat::<T>`.
+///
+/// ```
+/// use yabe::bb_table;
+///
+/// const REV: [u8; 256] = bb_table!(u8 => 256: rev);
+/// const SEXT: [i16; 256] = bb_table!(u8 => 256: s.e16);
+/// const CLAMP: [u8; 512] = bb_table!(u16 => 512: sat::<u8>);
+///
+/// assert_eq!(REV[0x01], 0x80);
+/// assert_eq!(SEXT[0xff], -1);
+/// assert_eq!(CLAMP[0x1ff], 0xff);
+/// ```
+#[macro_export]
+macro_rules! bb_table {
+    ($T:ident => $N:tt: $($chain:tt)+) => {{
+        let mut table = [$crate::__bb_const!(@ [$T] (0 as $T) . $($chain)+); $N];
+        let mut i = 0;
+        while i < $N {
+            table[i] = $crate::__bb_const!(@ [$T] (i as $T) . $($chain)+);
+            i += 1;
+        }
+        table
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bb_const {
+    (@ [i8] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i8] ($e) $($rest)*)
+    };
+    (@ [u8] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i8] ($e as i8) $($rest)*)
+    };
+    (@ [i16] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i16] ($e) $($rest)*)
+    };
+    (@ [u16] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i16] ($e as i16) $($rest)*)
+    };
+    (@ [i32] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i32] ($e) $($rest)*)
+    };
+    (@ [u32] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i32] ($e as i32) $($rest)*)
+    };
+    (@ [i64] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i64] ($e) $($rest)*)
+    };
+    (@ [u64] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i64] ($e as i64) $($rest)*)
+    };
+    (@ [i128] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i128] ($e) $($rest)*)
+    };
+    (@ [u128] ($e:expr) . s $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i128] ($e as i128) $($rest)*)
+    };
+    (@ [i8] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u8] ($e as u8) $($rest)*)
+    };
+    (@ [u8] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u8] ($e) $($rest)*)
+    };
+    (@ [i16] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u16] ($e as u16) $($rest)*)
+    };
+    (@ [u16] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u16] ($e) $($rest)*)
+    };
+    (@ [i32] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u32] ($e as u32) $($rest)*)
+    };
+    (@ [u32] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u32] ($e) $($rest)*)
+    };
+    (@ [i64] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u64] ($e as u64) $($rest)*)
+    };
+    (@ [u64] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u64] ($e) $($rest)*)
+    };
+    (@ [i128] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u128] ($e as u128) $($rest)*)
+    };
+    (@ [u128] ($e:expr) . u $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u128] ($e) $($rest)*)
+    };
+    (@ [i16] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i8] ($e as i8) $($rest)*)
+    };
+    (@ [u16] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u8] ($e as u8) $($rest)*)
+    };
+    (@ [i32] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i8] ($e as i8) $($rest)*)
+    };
+    (@ [u32] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u8] ($e as u8) $($rest)*)
+    };
+    (@ [i64] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i8] ($e as i8) $($rest)*)
+    };
+    (@ [u64] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u8] ($e as u8) $($rest)*)
+    };
+    (@ [i128] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i8] ($e as i8) $($rest)*)
+    };
+    (@ [u128] ($e:expr) . t8 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u8] ($e as u8) $($rest)*)
+    };
+    (@ [i32] ($e:expr) . t16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i16] ($e as i16) $($rest)*)
+    };
+    (@ [u32] ($e:expr) . t16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u16] ($e as u16) $($rest)*)
+    };
+    (@ [i64] ($e:expr) . t16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i16] ($e as i16) $($rest)*)
+    };
+    (@ [u64] ($e:expr) . t16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u16] ($e as u16) $($rest)*)
+    };
+    (@ [i128] ($e:expr) . t16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i16] ($e as i16) $($rest)*)
+    };
+    (@ [u128] ($e:expr) . t16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u16] ($e as u16) $($rest)*)
+    };
+    (@ [i64] ($e:expr) . t32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i32] ($e as i32) $($rest)*)
+    };
+    (@ [u64] ($e:expr) . t32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u32] ($e as u32) $($rest)*)
+    };
+    (@ [i128] ($e:expr) . t32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i32] ($e as i32) $($rest)*)
+    };
+    (@ [u128] ($e:expr) . t32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u32] ($e as u32) $($rest)*)
+    };
+    (@ [i128] ($e:expr) . t64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i64] ($e as i64) $($rest)*)
+    };
+    (@ [u128] ($e:expr) . t64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u64] ($e as u64) $($rest)*)
+    };
+    (@ [i8] ($e:expr) . e16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i16] ($e as i16) $($rest)*)
+    };
+    (@ [u8] ($e:expr) . e16 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u16] ($e as u16) $($rest)*)
+    };
+    (@ [i8] ($e:expr) . e32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i32] ($e as i32) $($rest)*)
+    };
+    (@ [u8] ($e:expr) . e32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u32] ($e as u32) $($rest)*)
+    };
+    (@ [i16] ($e:expr) . e32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i32] ($e as i32) $($rest)*)
+    };
+    (@ [u16] ($e:expr) . e32 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u32] ($e as u32) $($rest)*)
+    };
+    (@ [i8] ($e:expr) . e64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i64] ($e as i64) $($rest)*)
+    };
+    (@ [u8] ($e:expr) . e64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u64] ($e as u64) $($rest)*)
+    };
+    (@ [i16] ($e:expr) . e64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i64] ($e as i64) $($rest)*)
+    };
+    (@ [u16] ($e:expr) . e64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u64] ($e as u64) $($rest)*)
+    };
+    (@ [i32] ($e:expr) . e64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i64] ($e as i64) $($rest)*)
+    };
+    (@ [u32] ($e:expr) . e64 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u64] ($e as u64) $($rest)*)
+    };
+    (@ [i8] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i128] ($e as i128) $($rest)*)
+    };
+    (@ [u8] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u128] ($e as u128) $($rest)*)
+    };
+    (@ [i16] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i128] ($e as i128) $($rest)*)
+    };
+    (@ [u16] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u128] ($e as u128) $($rest)*)
+    };
+    (@ [i32] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i128] ($e as i128) $($rest)*)
+    };
+    (@ [u32] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u128] ($e as u128) $($rest)*)
+    };
+    (@ [i64] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [i128] ($e as i128) $($rest)*)
+    };
+    (@ [u64] ($e:expr) . e128 $($rest:tt)*) => {
+        $crate::__bb_const!(@ [u128] ($e as u128) $($rest)*)
+    };
+    (@ [$T:ident] ($e:expr) . id $($rest:tt)*) => {
+        $crate::__bb_const!(@ [$T] ($e) $($rest)*)
+    };
+    (@ [$T:ident] ($e:expr) . rev $($rest:tt)*) => {
+        $crate::__bb_const!(@ [$T] ($e.reverse_bits()) $($rest)*)
+    };
+    (@ [$T:ident] ($e:expr) . sat::<$D:ident> $($rest:tt)*) => {
+        $crate::__bb_const!(@ [$D] ({
+            let v = $e;
+            // negative values fit into i128, and non-negative ones fit into u128
+            #[allow(unused_comparisons)]
+            let negative = v < 0 as $T;
+            if negative {
+                if (v as i128) < <$D>::MIN as i128 { <$D>::MIN } else { v as $D }
+            } else if v as u128 > <$D>::MAX as u128 {
+                <$D>::MAX
+            } else {
+                v as $D
+            }
+        }) $($rest)*)
+    };
+    (@ [$T:ident] ($e:expr)) => {
+        $e
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bb;
+
+    const REV: [u8; 256] = bb_table!(u8 => 256: rev);
+
+    #[test]
+    fn bb_table() {
+        for (i, &x) in REV.iter().enumerate() {
+            assert_eq!(x, i as u8 * Bb.rev);
+        }
+        let sext: [i32; 256] = bb_table!(u8 => 256: s.e32);
+        for (i, &x) in sext.iter().enumerate() {
+            assert_eq!(x, i as u8 * Bb.s.e32);
+        }
+        const ID: [u16; 4] = bb_table!(u16 => 4: id);
+        assert_eq!(ID, [0, 1, 2, 3]);
+        const LOW: [u8; 16] = bb_table!(u16 => 16: e64.rev.t8.u.rev);
+        for (i, &x) in LOW.iter().enumerate() {
+            assert_eq!(x, i as u16 * Bb.e64.rev.t8.u.rev);
+        }
+    }
+
+    #[test]
+    fn bb_table_sat() {
+        const CLAMP: [u8; 256] = bb_table!(u16 => 256: sat::<u8>);
+        for (i, &x) in CLAMP.iter().enumerate() {
+            assert_eq!(x, i as u16 * Bb.sat::<u8>());
+        }
+        const WIDE: [u8; 1024] = bb_table!(u16 => 1024: sat::<u8>);
+        for (i, &x) in WIDE.iter().enumerate() {
+            assert_eq!(x, i as u16 * Bb.sat::<u8>());
+        }
+        const SIGNED: [i8; 256] = bb_table!(u8 => 256: s.e16.sat::<i8>);
+        for (i, &x) in SIGNED.iter().enumerate() {
+            assert_eq!(x, i as u8 * Bb.s.e16.sat::<i8>());
+        }
+        const UNSIGN: [u8; 256] = bb_table!(u8 => 256: s.sat::<u8>);
+        for (i, &x) in UNSIGN.iter().enumerate() {
+            assert_eq!(x, i as u8 * Bb.s.sat::<u8>());
+        }
+        const SWAP: [i128; 256] = bb_table!(u8 => 256: e128.rev.sat::<i128>);
+        for (i, &x) in SWAP.iter().enumerate() {
+            assert_eq!(x, i as u8 * Bb.e128.rev.sat::<i128>());
+        }
+        const FROM_SIGNED128: [u64; 256] = bb_table!(u8 => 256: s.e128.sat::<u64>);
+        for (i, &x) in FROM_SIGNED128.iter().enumerate() {
+            assert_eq!(x, i as u8 * Bb.s.e128.sat::<u64>());
+        }
+    }
+}
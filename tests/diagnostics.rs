@@ -0,0 +1,54 @@
+//! Checks the exact wording of the compile errors guiding users through mismatched inputs.
+//!
+//! Every case is compiled in a scratch crate depending on yabe, all in one function body,
+//! and each expected message must appear as the header of an `E0277` error.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Expressions which must fail to compile, with the error message each one produces.
+const CASES: &[(&str, &str)] = &[
+    ("5i8 * Bb.truncate_to::<i32>()", "`i8` can't be truncated to `i32`"),
+    ("5u16 * Bb.u.truncate_to::<u64>()", "`u16` can't be truncated to `u64`"),
+    ("5i32 * Bb.extend_to::<i8>()", "`i32` can't be extended to `i8`"),
+    ("5u8 * Bb.trunc::<9>()", "`u8` can't be truncated to `u16`"),
+    ("-5i64 * Bb.trunc::<65>()", "`i64` can't be truncated to `u128`"),
+    ("Bb::f2i::<u8, NanZero>(5u32)", "`f2i` requires an `f32` or `f64` input and an integer target, not `u32` to `u8`"),
+    ("Bb::f2i::<char, NanZero>(5f32)", "`f2i` requires an `f32` or `f64` input and an integer target, not `f32` to `char`"),
+    ("5u32 * Bb.isnan", "`isnan` requires an `f32` or `f64` input, not `u32`"),
+    ("5i64 * Bb.isfinite", "`isfinite` requires an `f32` or `f64` input, not `i64`"),
+    ("5u8 * Bb.u.issubnormal", "`issubnormal` requires an `f32` or `f64` input, not `u8`"),
+    ("Bb::ulps(1u32, 2)", "`ulps` requires `f32` or `f64` inputs, not `u32`"),
+    ("5f64 * Bb.f16_rne", "`f16_rne` and `f16_rtz` require an `f32` input, not `f64`"),
+    ("5u32 * Bb.f16_rtz", "`f16_rne` and `f16_rtz` require an `f32` input, not `u32`"),
+];
+
+#[test]
+fn diagnostics() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("diagnostics");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let manifest = format!(
+        "[package]\nname = \"diagnostics\"\nedition = \"2021\"\npublish = false\n\n\
+         [dependencies]\nyabe = {{ path = {:?} }}\n\n[workspace]\n",
+        env!("CARGO_MANIFEST_DIR"),
+    );
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    let body: String = CASES.iter().map(|(expr, _)| format!("    let _ = {expr};\n")).collect();
+    fs::write(dir.join("src/main.rs"), format!("use yabe::*;\n\nfn main() {{\n{body}}}\n")).unwrap();
+
+    let out = Command::new(option_env!("CARGO").unwrap_or("cargo"))
+        .args(["check", "--quiet", "--offline", "--color=never"])
+        .current_dir(&dir)
+        .env_remove("CARGO_TARGET_DIR")
+        .output()
+        .unwrap();
+    assert!(!out.status.success(), "the cases compiled");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    let errors: Vec<_> = stderr.lines().filter(|l| l.starts_with("error[")).collect();
+    for (expr, msg) in CASES {
+        let header = format!("error[E0277]: {msg}");
+        assert!(errors.contains(&&*header), "`{expr}` didn't fail with `{header}`:\n{stderr}");
+    }
+    assert_eq!(errors.len(), CASES.len(), "unexpected errors:\n{stderr}");
+}
@@ -0,0 +1,181 @@
+//! Derive macros for [yabe](https://docs.rs/yabe).
+//!
+//! Use them through the `derive` feature of `yabe`, which reexports them.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenTree};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, GenericParam, Ident, Type, WherePredicate};
+
+const REPRS: [&str; 10] = ["i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128"];
+
+/// Implements `yabe::BbRepr` for a fieldless enum with an integer `#[repr]`,
+/// and lets its values start a `Bb` chain as their discriminants.
+#[proc_macro_derive(BbRepr)]
+pub fn derive_bb_repr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    bb_repr(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn int_repr(input: &DeriveInput) -> syn::Result<Ident> {
+    let mut repr = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident().filter(|ident| REPRS.contains(&&*ident.to_string())) {
+                repr = Some(ident.clone());
+            }
+            Ok(())
+        })?;
+    }
+    repr.ok_or_else(|| Error::new(Span::call_site(), "BbRepr requires an integer #[repr], like #[repr(u8)]"))
+}
+
+fn bb_repr(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(&input.ident, "BbRepr can only be derived for enums"));
+    };
+    if let Some(variant) = data.variants.iter().find(|variant| !matches!(variant.fields, Fields::Unit)) {
+        return Err(Error::new_spanned(variant, "BbRepr requires all variants to be fieldless"));
+    }
+    let repr = int_repr(&input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+    Ok(quote! {
+        impl #impl_generics ::yabe::BbRepr for #name #ty_generics #where_clause {
+            type Repr = #repr;
+
+            fn to_repr(self) -> #repr {
+                self as #repr
+            }
+
+            fn from_repr(repr: #repr) -> ::core::option::Option<Self> {
+                match repr {
+                    #(repr if repr == Self::#variants as #repr => ::core::option::Option::Some(Self::#variants),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        impl<Op, U> ::core::ops::Mul<::yabe::RL<Op, U>> for #name #ty_generics where #repr: ::core::ops::Mul<::yabe::RL<Op, U>> {
+            type Output = <#repr as ::core::ops::Mul<::yabe::RL<Op, U>>>::Output;
+
+            fn mul(self, rhs: ::yabe::RL<Op, U>) -> Self::Output {
+                (self as #repr) * rhs
+            }
+        }
+
+        impl<Op, U> ::core::ops::Mul<::yabe::RA<Op, U>> for #name #ty_generics where #repr: ::core::ops::Mul<::yabe::RA<Op, U>> {
+            type Output = <#repr as ::core::ops::Mul<::yabe::RA<Op, U>>>::Output;
+
+            fn mul(self, rhs: ::yabe::RA<Op, U>) -> Self::Output {
+                (self as #repr) * rhs
+            }
+        }
+    })
+}
+
+/// Lets a single-field tuple struct over an integer start a `Bb` chain, applying it to the field.
+///
+/// A struct generic over the field type, like `struct Sample<T>(T)`, wraps any output,
+/// so `Sample(1u8) * Bb.e32` is `Sample(1u32)`. Other structs keep their type,
+/// so only chains from the field type to itself apply to them.
+///
+/// Bounds on the type parameter are required of the wrapped output as well.
+/// Where clauses bounding other types which mention the parameter are rejected.
+#[proc_macro_derive(Bb)]
+pub fn derive_bb(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    bb(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn bb(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let field = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => return Err(Error::new_spanned(&input.ident, "Bb requires a tuple struct with a single field")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "Bb can only be derived for structs")),
+    };
+    let name = &input.ident;
+    let inner = &field.ty;
+    let param = match (input.generics.params.len(), input.generics.params.first(), inner) {
+        (1, Some(GenericParam::Type(param)), ty) if is_param(ty, &param.ident) => Some(param),
+        _ => None,
+    };
+    let impls = ["RL", "RA"].map(|link| {
+        let link = Ident::new(link, Span::call_site());
+        Ok(match param {
+            Some(param) => {
+                let ident = &param.ident;
+                let output = quote!(<#ident as ::core::ops::Mul<::yabe::#link<Op, U>>>::Output);
+                // The output wraps the result in the same struct, so it must meet the bounds on the field too.
+                let mut predicates = Vec::new();
+                if !param.bounds.is_empty() {
+                    let bounds = &param.bounds;
+                    predicates.push(quote!(#ident: #bounds));
+                    predicates.push(quote!(#output: #bounds));
+                }
+                for predicate in input.generics.where_clause.iter().flat_map(|w| &w.predicates) {
+                    match predicate {
+                        WherePredicate::Type(p) if p.lifetimes.is_none() && is_param(&p.bounded_ty, ident) => {
+                            let bounds = &p.bounds;
+                            predicates.push(quote!(#ident: #bounds));
+                            predicates.push(quote!(#output: #bounds));
+                        }
+                        p if mentions(quote!(#p), ident) => {
+                            return Err(Error::new_spanned(p, "Bb can only carry bounds placed on the field type itself"));
+                        }
+                        p => predicates.push(quote!(#p)),
+                    }
+                }
+                quote! {
+                    impl<#ident, Op, U> ::core::ops::Mul<::yabe::#link<Op, U>> for #name<#ident>
+                    where
+                        #ident: ::core::ops::Mul<::yabe::#link<Op, U>>,
+                        #(#predicates,)*
+                    {
+                        type Output = #name<#output>;
+
+                        fn mul(self, rhs: ::yabe::#link<Op, U>) -> Self::Output {
+                            #name(self.0 * rhs)
+                        }
+                    }
+                }
+            }
+            None => {
+                let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+                let params = input.generics.params.iter();
+                let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+                quote! {
+                    impl<#(#params,)* Op, U> ::core::ops::Mul<::yabe::#link<Op, U>> for #name #ty_generics
+                    where
+                        #inner: ::core::ops::Mul<::yabe::#link<Op, U>, Output = #inner>,
+                        #predicates
+                    {
+                        type Output = Self;
+
+                        fn mul(self, rhs: ::yabe::#link<Op, U>) -> Self {
+                            #name(self.0 * rhs)
+                        }
+                    }
+                }
+            }
+        })
+    });
+    let [rl, ra] = impls;
+    let (rl, ra) = (rl?, ra?);
+    Ok(quote! { #rl #ra })
+}
+
+fn is_param(ty: &Type, param: &Ident) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(param))
+}
+
+fn mentions(tokens: proc_macro2::TokenStream, param: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == *param,
+        TokenTree::Group(group) => mentions(group.stream(), param),
+        _ => false,
+    })
+}
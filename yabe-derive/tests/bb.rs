@@ -0,0 +1,37 @@
+use yabe::Bb;
+
+#[derive(Bb, Clone, Copy, Debug, PartialEq)]
+struct Port(u16);
+
+#[derive(Bb, Debug, PartialEq)]
+struct Sample<T>(T);
+
+#[derive(Bb, Debug, PartialEq)]
+struct Bounded<T: Copy>(T)
+where
+    T: Default;
+
+#[test]
+fn same_type() {
+    assert_eq!(Port(0x1f90) * Bb.swapbytes16, Port(0x901f));
+    assert_eq!(Port(0x1f90) * Bb.rev_if::<yabe::Little>(), Port(0x1f90));
+    assert_eq!(Port(0x8000) * Bb.shls(1), Port(0xffff));
+    let port = Port(80);
+    assert_eq!(port * Bb.swapbytes16 * Bb.swapbytes16, port);
+}
+
+#[test]
+fn generic() {
+    assert_eq!(Sample(0x1234u16) * Bb.swapbytes16, Sample(0x3412u16));
+    assert_eq!(Sample(-1i8) * Bb.e32, Sample(-1i32));
+    assert_eq!(Sample(-1i8) * Bb.u.e32, Sample(0xffu32));
+    assert_eq!(Sample(0x1_0000u32) * Bb.ct16, Sample(None));
+    assert_eq!(Sample(Port(1)) * Bb.swapbytes16, Sample(Port(0x100)));
+}
+
+#[test]
+fn bounded() {
+    assert_eq!(Bounded(-1i8) * Bb.e32, Bounded(-1i32));
+    assert_eq!(Bounded(0x1234u16) * Bb.swapbytes16 * Bb.u.e64, Bounded(0x3412u64));
+    assert_eq!(Bounded(0x1_0000u32) * Bb.ct16, Bounded(None));
+}
@@ -0,0 +1,48 @@
+use yabe::{Bb, BbRepr};
+
+#[derive(BbRepr, Clone, Copy, Debug, PartialEq)]
+#[repr(u16)]
+enum Opcode {
+    Nop,
+    Load = 0x100,
+    Store,
+    Halt = 0xffff,
+}
+
+#[derive(BbRepr, Debug, PartialEq)]
+#[repr(i8)]
+enum Sign {
+    Negative = -1,
+    Zero,
+    Positive,
+}
+
+#[test]
+fn to_repr() {
+    assert_eq!(Opcode::Nop.to_repr(), 0);
+    assert_eq!(Opcode::Load.to_repr(), 0x100);
+    assert_eq!(Opcode::Store.to_repr(), 0x101);
+    assert_eq!(Bb::to_repr(Opcode::Halt), 0xffff);
+    assert_eq!(Sign::Negative.to_repr(), -1);
+}
+
+#[test]
+fn from_repr() {
+    assert_eq!(Opcode::from_repr(0), Some(Opcode::Nop));
+    assert_eq!(Opcode::from_repr(0x101), Some(Opcode::Store));
+    assert_eq!(Bb::from_repr::<Opcode>(0xffff), Some(Opcode::Halt));
+    assert_eq!(Opcode::from_repr(1), None);
+    assert_eq!(Opcode::from_repr(0x102), None);
+    assert_eq!(Sign::from_repr(1), Some(Sign::Positive));
+    assert_eq!(Sign::from_repr(2), None);
+}
+
+#[test]
+fn chain() {
+    assert_eq!(Opcode::Store * Bb.t8, 0x01);
+    assert_eq!(Opcode::Halt * Bb.s.e32, -1);
+    assert_eq!(Opcode::Load * Bb.swapbytes16, 0x0001);
+    assert_eq!(Sign::Negative * Bb.u.e16, 0xff);
+    assert_eq!(Opcode::Store * Bb.ct8, None);
+    assert_eq!(Bb::from_repr::<Opcode>(0x1_0101u32 * Bb.t16), Some(Opcode::Store));
+}